@@ -0,0 +1,284 @@
+//! Criterion-style timings of the parse phase and both parts of every day.
+//!
+//! Every phase is warmed up and then sampled repeatedly, the median sample is reported. Timings
+//! can be saved as a named baseline under `target/bench/` and compared against later runs.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Write},
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use common::{
+    input::{InputProvider, Variant},
+    Answer, Solution,
+};
+
+use crate::days;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub warm_up: Duration,
+    pub measurement: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(200),
+            measurement: Duration::from_secs(1),
+            min_samples: 5,
+            max_samples: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    /// Identifier of the phase in baseline files.
+    fn key(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "parse" => Some(Phase::Parse),
+            "part1" => Some(Phase::Part1),
+            "part2" => Some(Phase::Part2),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    pub median: Duration,
+    pub samples: usize,
+}
+
+/// Timings of the phases of one day. Unsolved parts are left out.
+pub type DayTimings = Vec<(Phase, Timing)>;
+
+/// Median phase durations of a previous run, keyed by day and phase.
+pub type Baseline = HashMap<(u8, Phase), Duration>;
+
+fn sample<T>(config: &Config, mut f: impl FnMut() -> T) -> Timing {
+    let start = Instant::now();
+    while start.elapsed() < config.warm_up {
+        black_box(f());
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+    while samples.len() < config.min_samples
+        || (start.elapsed() < config.measurement && samples.len() < config.max_samples)
+    {
+        let sample_start = Instant::now();
+        black_box(f());
+        samples.push(sample_start.elapsed());
+    }
+
+    samples.sort_unstable();
+    Timing {
+        median: samples[samples.len() / 2],
+        samples: samples.len(),
+    }
+}
+
+/// Times parsing and both parts of a solution. Parts are timed on an input that was parsed once.
+pub fn measure<S: Solution>(input: &str, config: &Config) -> Result<DayTimings, String> {
+    let parsed = S::parse(input).map_err(|err| format!("Failed to parse input: {err}"))?;
+
+    let mut timings = vec![
+        (Phase::Parse, sample(config, || S::parse(input))),
+        (Phase::Part1, sample(config, || S::part1(&parsed))),
+    ];
+    if S::part2(&parsed) != Answer::Unsolved {
+        timings.push((Phase::Part2, sample(config, || S::part2(&parsed))));
+    }
+    Ok(timings)
+}
+
+/// Benchmarks the given days on their real input. Days without an input are skipped.
+pub fn bench_days(
+    days: &[u8],
+    inputs: &InputProvider,
+    config: &Config,
+) -> Result<Vec<(u8, DayTimings)>, String> {
+    let mut results = vec![];
+    for &day in days {
+        let solution = days::get(day).ok_or_else(|| format!("Day {day} is not solved yet"))?;
+        let input = match inputs.resolve(day, &Variant::Real) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Skipping day {day}: {err}");
+                continue;
+            }
+        };
+        results.push((day, (solution.bench)(&input, config)?));
+    }
+    Ok(results)
+}
+
+/// Where the baseline `name` is saved.
+pub fn baseline_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join("bench")
+        .join(format!("{name}.txt"))
+}
+
+/// Saves the medians as `<day> <phase> <nanoseconds>` lines.
+pub fn save_baseline(name: &str, results: &[(u8, DayTimings)]) -> Result<(), String> {
+    let path = baseline_path(name);
+    let mut contents = String::new();
+    for (day, timings) in results {
+        for (phase, timing) in timings {
+            writeln!(
+                contents,
+                "{day} {} {}",
+                phase.key(),
+                timing.median.as_nanos()
+            )
+            .expect("Writing to a string should not fail");
+        }
+    }
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| format!("Failed to create {}: {err}", dir.display()))?;
+    }
+    std::fs::write(&path, contents)
+        .map_err(|err| format!("Failed to write {}: {err}", path.display()))
+}
+
+pub fn load_baseline(name: &str) -> Result<Baseline, String> {
+    let path = baseline_path(name);
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|err| format!("Failed to read baseline {}: {err}", path.display()))?;
+
+    contents
+        .lines()
+        .map(|line| {
+            let mut split = line.split_ascii_whitespace();
+            let day = split.next().and_then(|day| day.parse().ok());
+            let phase = split.next().and_then(Phase::from_key);
+            let nanos = split.next().and_then(|nanos| nanos.parse().ok());
+            match (day, phase, nanos) {
+                (Some(day), Some(phase), Some(nanos)) => {
+                    Ok(((day, phase), Duration::from_nanos(nanos)))
+                }
+                _ => Err(format!(
+                    "Invalid line in baseline {}: `{line}`",
+                    path.display()
+                )),
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Comparison {
+    pub day: u8,
+    pub phase: Phase,
+    pub current: Duration,
+    pub baseline: Option<Duration>,
+}
+
+impl Comparison {
+    /// Relative change against the baseline in percent. Positive values are slowdowns.
+    pub fn change(&self) -> Option<f64> {
+        self.baseline
+            .map(|baseline| (self.current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+pub fn compare(results: &[(u8, DayTimings)], baseline: Option<&Baseline>) -> Vec<Comparison> {
+    results
+        .iter()
+        .flat_map(|(day, timings)| {
+            timings.iter().map(move |&(phase, timing)| Comparison {
+                day: *day,
+                phase,
+                current: timing.median,
+                baseline: baseline.and_then(|baseline| baseline.get(&(*day, phase)).copied()),
+            })
+        })
+        .collect()
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{nanos:.0} ns")
+    } else if nanos < 1e6 {
+        format!("{:.2} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else {
+        format!("{:.2} s", nanos / 1e9)
+    }
+}
+
+/// Renders the comparisons as a table. Changes above `threshold` percent are marked.
+pub fn render(comparisons: &[Comparison], threshold: f64) -> String {
+    let mut table = format!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>9}\n",
+        "day", "phase", "time", "baseline", "change"
+    );
+    for comparison in comparisons {
+        let baseline = comparison
+            .baseline
+            .map(format_duration)
+            .unwrap_or_else(|| "-".to_owned());
+        let change = comparison
+            .change()
+            .map(|change| format!("{change:+.1}%"))
+            .unwrap_or_else(|| "-".to_owned());
+        let marker = if comparison.is_regression(threshold) {
+            "  regressed"
+        } else {
+            ""
+        };
+        writeln!(
+            table,
+            "{:>3}  {:<6}  {:>12}  {:>12}  {:>9}{marker}",
+            comparison.day,
+            comparison.phase.to_string(),
+            format_duration(comparison.current),
+            baseline,
+            change,
+        )
+        .expect("Writing to a string should not fail");
+    }
+    table
+}
@@ -0,0 +1,61 @@
+use common::{Answer, Part, Solution};
+
+use crate::bench::{self, Config, DayTimings};
+
+pub type Solve = fn(&str, &[Part]) -> Result<Vec<Answer>, String>;
+pub type Bench = fn(&str, &Config) -> Result<DayTimings, String>;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub solve: Solve,
+    /// Solves the example from the puzzle text, see [`Solution::parse_example`].
+    pub solve_example: Solve,
+    pub bench: Bench,
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            solve: <$solution>::solve,
+            solve_example: <$solution>::solve_example,
+            bench: bench::measure::<$solution>,
+        }
+    };
+}
+
+/// Looks up the solution of a day. Days that aren't solved yet return `None`.
+pub fn get(day: u8) -> Option<Day> {
+    match day {
+        1 => Some(day!(day_1::Day1)),
+        2 => Some(day!(day_2::Day2)),
+        3 => Some(day!(day_3::Day3)),
+        4 => Some(day!(day_4::Day4)),
+        5 => Some(day!(day_5::Day5)),
+        6 => Some(day!(day_6::Day6)),
+        7 => Some(day!(day_7::Day7)),
+        8 => Some(day!(day_8::Day8)),
+        9 => Some(day!(day_9::Day9)),
+        10 => Some(day!(day_10::Day10)),
+        11 => Some(day!(day_11::Day11)),
+        12 => Some(day!(day_12::Day12)),
+        13 => Some(day!(day_13::Day13)),
+        14 => Some(day!(day_14::Day14)),
+        15 => Some(day!(day_15::Day15)),
+        16 => Some(day!(day_16::Day16)),
+        17 => Some(day!(day_17::Day17)),
+        18 => Some(day!(day_18::Day18)),
+        19 => Some(day!(day_19::Day19)),
+        20 => Some(day!(day_20::Day20)),
+        21 => Some(day!(day_21::Day21)),
+        22 => Some(day!(day_22::Day22)),
+        23 => Some(day!(day_23::Day23)),
+        24 => Some(day!(day_24::Day24)),
+        25 => Some(day!(day_25::Day25)),
+        _ => None,
+    }
+}
+
+/// All days that are solved so far.
+pub fn solved() -> impl Iterator<Item = (u8, Day)> {
+    (1..=25).filter_map(|day| get(day).map(|solution| (day, solution)))
+}
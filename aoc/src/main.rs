@@ -0,0 +1,196 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc::{bench, days, verify};
+use clap::{Parser, Subcommand};
+use common::{
+    input::{HttpFetcher, InputProvider, Variant},
+    Part,
+};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Directory for fetched inputs. Defaults to `target/inputs`
+    #[arg(long, global = true)]
+    cache_dir: Option<PathBuf>,
+    /// Fetches missing real inputs from `<URL>/2024/day/<day>/input` over plain HTTP
+    #[arg(long, global = true, env = "AOC_INPUT_URL")]
+    fetch_url: Option<String>,
+    /// Session cookie sent along when fetching inputs
+    #[arg(long, global = true, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+}
+
+impl Cli {
+    fn inputs(&self) -> Result<InputProvider, String> {
+        let mut inputs = InputProvider::default();
+        if let Some(cache_dir) = &self.cache_dir {
+            inputs = inputs.with_cache_dir(cache_dir);
+        }
+        if let Some(url) = &self.fetch_url {
+            inputs = inputs.with_fetcher(HttpFetcher::new(url, self.session.clone())?);
+        }
+        Ok(inputs)
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs one or both parts of a day
+    Run {
+        /// Day of the puzzle (1-25)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part of the puzzle. Runs both parts if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file. Defaults to the real input of the day
+        #[arg(long, conflicts_with_all = ["small", "stdin"])]
+        input: Option<PathBuf>,
+        /// Uses the example from the puzzle text, `day_N/data/input_small.txt`
+        #[arg(long, conflicts_with = "stdin")]
+        small: bool,
+        /// Reads the input from stdin
+        #[arg(long)]
+        stdin: bool,
+    },
+    /// Checks the solutions against the answers recorded in `day_N/data/answers.txt`
+    Verify {
+        /// Only verify this day. Verifies every solved day if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+    /// Times parsing and both parts of the solved days on their real input
+    Bench {
+        /// Only benchmark this day. Benchmarks every solved day if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Saves the timings under this name to compare against later
+        #[arg(long)]
+        save_baseline: Option<String>,
+        /// Compares the timings against a saved baseline
+        #[arg(long)]
+        baseline: Option<String>,
+        /// Slowdown in percent above which a phase counts as regressed
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+fn run(inputs: &InputProvider, day: u8, part: Option<u8>, variant: Variant) -> Result<(), String> {
+    let solution = days::get(day).ok_or_else(|| format!("Day {day} is not solved yet"))?;
+    let contents = inputs.resolve(day, &variant)?;
+
+    let parts = match part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => vec![Part::One, Part::Two],
+    };
+    let solve = match variant {
+        Variant::Small => solution.solve_example,
+        _ => solution.solve,
+    };
+    let answers = solve(&contents, &parts)?;
+    for (part, answer) in parts.iter().zip(answers) {
+        println!("Day {day}, part {part}: {answer}");
+    }
+    Ok(())
+}
+
+fn selected_days(day: Option<u8>) -> Vec<u8> {
+    match day {
+        Some(day) => vec![day],
+        None => days::solved().map(|(day, _)| day).collect(),
+    }
+}
+
+fn run_verify(day: Option<u8>) -> Result<(), String> {
+    let days = selected_days(day);
+
+    let mut checks = vec![];
+    for day in days {
+        checks.extend(verify::verify_day(day)?);
+    }
+    for check in &checks {
+        println!("{check}");
+    }
+
+    let failed = checks.iter().filter(|check| !check.passed()).count();
+    println!("{} passed, {} failed", checks.len() - failed, failed);
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format!("{failed} check(s) failed"))
+    }
+}
+
+fn run_bench(
+    inputs: &InputProvider,
+    day: Option<u8>,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+) -> Result<(), String> {
+    let baseline = baseline
+        .map(|name| bench::load_baseline(&name))
+        .transpose()?;
+    let results = bench::bench_days(&selected_days(day), inputs, &bench::Config::default())?;
+
+    let comparisons = bench::compare(&results, baseline.as_ref());
+    print!("{}", bench::render(&comparisons, threshold));
+
+    if let Some(name) = save_baseline {
+        bench::save_baseline(&name, &results)?;
+    }
+
+    let regressed = comparisons
+        .iter()
+        .filter(|comparison| comparison.is_regression(threshold))
+        .count();
+    if regressed == 0 {
+        Ok(())
+    } else {
+        Err(format!(
+            "{regressed} phase(s) regressed by more than {threshold}%"
+        ))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = cli.inputs().and_then(|inputs| match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            small,
+            stdin,
+        } => {
+            let variant = match (input, small, stdin) {
+                (Some(path), _, _) => Variant::Path(path),
+                (None, true, _) => Variant::Small,
+                (None, false, true) => Variant::Stdin,
+                (None, false, false) => Variant::Real,
+            };
+            run(&inputs, day, part, variant)
+        }
+        Command::Verify { day } => run_verify(day),
+        Command::Bench {
+            day,
+            save_baseline,
+            baseline,
+            threshold,
+        } => run_bench(&inputs, day, save_baseline, baseline, threshold),
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
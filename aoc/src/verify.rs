@@ -0,0 +1,147 @@
+//! Checks the solutions against the answers recorded in `day_N/data/answers.txt`.
+//!
+//! Every line of an answers file has the form `<input file> <part> <answer>`, e.g.
+//! `input_small.txt 2 co,de,ka,ta`. Empty lines and lines starting with `#` are ignored.
+//!
+//! Inputs whose name starts with `input_small` are examples from the puzzle text and are parsed
+//! as such, see [`common::Solution::parse_example`].
+
+use std::fmt::{Display, Formatter};
+
+use common::{input, Part};
+
+use crate::days;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { actual: String },
+    Error(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub expected: Expected,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Pass
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, part {} ({}): ",
+            self.day, self.expected.part, self.expected.input
+        )?;
+        match &self.outcome {
+            Outcome::Pass => write!(f, "ok"),
+            Outcome::Fail { actual } => write!(
+                f,
+                "FAIL\n  expected: {}\n  actual:   {}",
+                self.expected.answer, actual
+            ),
+            Outcome::Error(err) => write!(f, "ERROR {err}"),
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Option<Expected> {
+    let mut split = line.splitn(3, ' ');
+    let input = split.next()?;
+    let part = match split.next()? {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return None,
+    };
+    let answer = split.next()?.trim();
+    if answer.is_empty() {
+        return None;
+    }
+    Some(Expected {
+        input: input.to_owned(),
+        part,
+        answer: answer.to_owned(),
+    })
+}
+
+/// Reads the recorded answers of a day. A day without an answers file has no expectations.
+pub fn expected(day: u8) -> Result<Vec<Expected>, String> {
+    let path = input::data_dir(day).join("answers.txt");
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| {
+            parse_line(line).ok_or_else(|| {
+                format!(
+                    "{}:{}: expected `<input file> <part> <answer>`, found `{line}`",
+                    path.display(),
+                    idx + 1
+                )
+            })
+        })
+        .collect()
+}
+
+/// Solves every recorded input of a day and compares the answers. Each input is parsed once.
+pub fn verify_day(day: u8) -> Result<Vec<Check>, String> {
+    let solution = days::get(day).ok_or_else(|| format!("Day {day} is not solved yet"))?;
+    let expected = expected(day)?;
+
+    let mut inputs: Vec<&str> = vec![];
+    for e in &expected {
+        if !inputs.contains(&e.input.as_str()) {
+            inputs.push(&e.input);
+        }
+    }
+
+    let mut checks = vec![];
+    for input in inputs {
+        let expected: Vec<_> = expected.iter().filter(|e| e.input == input).collect();
+        let parts: Vec<_> = expected.iter().map(|e| e.part).collect();
+
+        let solve = match input.starts_with("input_small") {
+            true => solution.solve_example,
+            false => solution.solve,
+        };
+        let path = input::data_dir(day).join(input);
+        let answers = std::fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read {}: {err}", path.display()))
+            .and_then(|contents| solve(&contents, &parts));
+
+        for (idx, expected) in expected.into_iter().enumerate() {
+            let outcome = match &answers {
+                Ok(answers) if answers[idx].to_string() == expected.answer => Outcome::Pass,
+                Ok(answers) => Outcome::Fail {
+                    actual: answers[idx].to_string(),
+                },
+                Err(err) => Outcome::Error(err.clone()),
+            };
+            checks.push(Check {
+                day,
+                expected: expected.clone(),
+                outcome,
+            });
+        }
+    }
+    Ok(checks)
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use aoc::bench::{self, Baseline, Comparison, DayTimings, Phase, Timing};
+
+fn timing(micros: u64) -> Timing {
+    Timing {
+        median: Duration::from_micros(micros),
+        samples: 5,
+    }
+}
+
+fn comparison(phase: Phase, current: u64, baseline: Option<u64>) -> Comparison {
+    Comparison {
+        day: 1,
+        phase,
+        current: Duration::from_micros(current),
+        baseline: baseline.map(Duration::from_micros),
+    }
+}
+
+/// The rendered row of `phase`, split into its columns.
+fn row(table: &str, phase: &str) -> Vec<String> {
+    let line = table
+        .lines()
+        .find(|line| line.contains(phase))
+        .expect("Phase should have a row");
+    line.split("  ")
+        .map(str::trim)
+        .filter(|column| !column.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn baseline_round_trips() {
+    let results: Vec<(u8, DayTimings)> = vec![
+        (
+            1,
+            vec![(Phase::Parse, timing(12)), (Phase::Part1, timing(345))],
+        ),
+        (24, vec![(Phase::Part2, timing(6_789))]),
+    ];
+    bench::save_baseline("test-round-trip", &results).expect("Baseline should be saved");
+
+    let expected = Baseline::from([
+        ((1, Phase::Parse), Duration::from_micros(12)),
+        ((1, Phase::Part1), Duration::from_micros(345)),
+        ((24, Phase::Part2), Duration::from_micros(6_789)),
+    ]);
+    assert_eq!(bench::load_baseline("test-round-trip"), Ok(expected));
+}
+
+#[test]
+fn rejects_malformed_baseline_line() {
+    let path = bench::baseline_path("test-malformed");
+    std::fs::create_dir_all(path.parent().expect("Baseline should be in a directory"))
+        .expect("Baseline directory should be created");
+    std::fs::write(&path, "1 parse 1200\n1 part3 1500\n").expect("Baseline should be written");
+
+    let err = bench::load_baseline("test-malformed").expect_err("Baseline should be rejected");
+    assert!(err.contains("Invalid line"), "{err}");
+    assert!(err.contains("`1 part3 1500`"), "{err}");
+}
+
+#[test]
+fn renders_missing_baseline_entry_as_dash() {
+    let results: Vec<(u8, DayTimings)> = vec![(
+        1,
+        vec![(Phase::Part1, timing(2_000)), (Phase::Part2, timing(3_000))],
+    )];
+    let baseline = Baseline::from([((1, Phase::Part1), Duration::from_micros(1_000))]);
+    let comparisons = bench::compare(&results, Some(&baseline));
+    assert_eq!(comparisons[1].baseline, None);
+    assert_eq!(comparisons[1].change(), None);
+
+    let table = bench::render(&comparisons, 10.0);
+    assert_eq!(row(&table, "part 2"), ["1", "part 2", "3.00 ms", "-", "-"]);
+    assert_eq!(
+        row(&table, "part 1"),
+        ["1", "part 1", "2.00 ms", "1.00 ms", "+100.0%", "regressed"]
+    );
+}
+
+#[test]
+fn marks_only_changes_above_threshold_as_regressed() {
+    let comparisons = [
+        comparison(Phase::Parse, 1_050, Some(1_000)),
+        comparison(Phase::Part1, 1_200, Some(1_000)),
+        comparison(Phase::Part2, 800, Some(1_000)),
+    ];
+    assert!(!comparisons[0].is_regression(10.0));
+    assert!(comparisons[1].is_regression(10.0));
+    assert!(!comparisons[2].is_regression(10.0));
+
+    let table = bench::render(&comparisons, 10.0);
+    assert_eq!(table.matches("regressed").count(), 1);
+    assert!(row(&table, "part 1").ends_with(&["+20.0%".to_owned(), "regressed".to_owned()]));
+    assert_eq!(row(&table, "part 2")[4], "-20.0%");
+}
@@ -0,0 +1,86 @@
+use aoc::days;
+use common::Part;
+
+fn parse_error(day: u8, input: &str) -> String {
+    let day = days::get(day).expect("Day should be solved");
+    (day.solve)(input, &[Part::One]).expect_err("Input should be rejected")
+}
+
+#[test]
+fn reports_line_and_column_of_bad_token() {
+    assert_eq!(
+        parse_error(2, "7 6 4 2 1\n1 2 x 8 9\n"),
+        "Failed to parse input: line 2, column 5: expected a level\n\
+         2 | 1 2 x 8 9\n  |     ^"
+    );
+}
+
+#[test]
+fn reports_missing_token_at_end_of_line() {
+    assert_eq!(
+        parse_error(7, "190: 10 19\n3267 81 40 27"),
+        "Failed to parse input: line 2, column 14: expected `: `\n\
+         2 | 3267 81 40 27\n  |              ^"
+    );
+}
+
+#[test]
+fn rejects_report_of_a_single_level_instead_of_panicking() {
+    assert!(parse_error(2, "1 2\n3").contains("line 2, column 2: expected at least two levels"));
+    assert!(parse_error(2, "1 2\n\n").contains("line 2, column 1: expected at least two levels"));
+}
+
+#[test]
+fn rejects_update_without_middle_page_instead_of_panicking() {
+    assert!(parse_error(5, "1|2\n\n1,2\n")
+        .contains("line 3, column 4: expected an odd number of pages"));
+}
+
+#[test]
+fn rejects_equation_without_values_instead_of_panicking() {
+    assert!(parse_error(7, "190: 10 19\n5: ").contains("line 2, column 4: expected a value"));
+}
+
+#[test]
+fn rejects_stone_too_large_for_a_number() {
+    assert!(parse_error(11, "125 99999999999999999999999\n")
+        .contains("line 1, column 5: expected a number"));
+}
+
+#[test]
+fn rejects_half_box_in_a_narrow_warehouse() {
+    assert!(parse_error(15, "#####\n#@[]#\n#####\n\n>\n")
+        .contains("line 2, column 3: expected one of `.`, `#`, `O` or `@`"));
+}
+
+#[test]
+fn reports_invalid_tile_of_grid() {
+    assert_eq!(
+        parse_error(4, "XMAS\nXMBS\n"),
+        "Failed to parse input: line 2, column 3: expected one of `X`, `M`, `A` or `S`\n\
+         2 | XMBS\n  |   ^"
+    );
+}
+
+#[test]
+fn rejects_ragged_grid() {
+    assert!(parse_error(6, "..#.\n.^.\n").contains("line 2, column 4: expected a line of 4 tiles"));
+}
+
+#[test]
+fn rejects_empty_disk_map_instead_of_panicking() {
+    assert!(parse_error(9, "\n").contains("line 1, column 1: expected a digit"));
+}
+
+#[test]
+fn rejects_unknown_gate_instead_of_panicking() {
+    assert!(parse_error(24, "x00: 1\ny00: 0\n\nx00 NAND y00 -> z00\n")
+        .contains("line 4, column 5: expected `AND`, `OR` or `XOR`"));
+}
+
+#[test]
+fn rejects_unknown_schematic_character_instead_of_panicking() {
+    assert!(
+        parse_error(25, "#####\n.####\n..o..\n").contains("line 3, column 3: expected `.` or `#`")
+    );
+}
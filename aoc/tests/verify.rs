@@ -0,0 +1,48 @@
+use aoc::verify;
+
+macro_rules! verify_days {
+    ($($name:ident: $day:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let checks = verify::verify_day($day).expect("Should be able to verify the day");
+                assert!(!checks.is_empty(), "day {} has no recorded answers", $day);
+
+                let failures: Vec<_> = checks
+                    .iter()
+                    .filter(|check| !check.passed())
+                    .map(|check| check.to_string())
+                    .collect();
+                assert!(failures.is_empty(), "{}", failures.join("\n"));
+            }
+        )*
+    };
+}
+
+verify_days! {
+    day_1: 1,
+    day_2: 2,
+    day_3: 3,
+    day_4: 4,
+    day_5: 5,
+    day_6: 6,
+    day_7: 7,
+    day_8: 8,
+    day_9: 9,
+    day_10: 10,
+    day_11: 11,
+    day_12: 12,
+    day_13: 13,
+    day_14: 14,
+    day_15: 15,
+    day_16: 16,
+    day_17: 17,
+    day_18: 18,
+    day_19: 19,
+    day_20: 20,
+    day_21: 21,
+    day_22: 22,
+    day_23: 23,
+    day_24: 24,
+    day_25: 25,
+}
@@ -0,0 +1,188 @@
+//! Finds the input of a day, independent of the working directory.
+//!
+//! Real inputs are looked up in `day_N/data/input.txt`, then in the cache directory and finally
+//! fetched with the configured [`Fetcher`], which stores them in the cache for the next run.
+
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    path::{Path, PathBuf},
+};
+
+/// Which input of a day to use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Variant {
+    /// The personal puzzle input.
+    Real,
+    /// The example from the puzzle text, `day_N/data/input_small.txt`.
+    Small,
+    Path(PathBuf),
+    Stdin,
+}
+
+/// Downloads the real input of a day.
+pub trait Fetcher {
+    fn fetch(&self, day: u8) -> Result<String, String>;
+}
+
+/// Fetches `<base>/2024/day/<day>/input` over plain HTTP, e.g. from a local mirror.
+///
+/// The session cookie is sent along if there is one.
+#[derive(Debug, Clone)]
+pub struct HttpFetcher {
+    host: String,
+    prefix: String,
+    session: Option<String>,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: &str, session: Option<String>) -> Result<Self, String> {
+        let rest = base_url
+            .strip_prefix("http://")
+            .ok_or_else(|| format!("Only http:// URLs can be fetched, got `{base_url}`"))?;
+        let (host, prefix) = match rest.find('/') {
+            Some(idx) => rest.split_at(idx),
+            None => (rest, ""),
+        };
+        let host = if host.contains(':') {
+            host.to_owned()
+        } else {
+            format!("{host}:80")
+        };
+        Ok(Self {
+            host,
+            prefix: prefix.trim_end_matches('/').to_owned(),
+            session,
+        })
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, day: u8) -> Result<String, String> {
+        let path = format!("{}/2024/day/{day}/input", self.prefix);
+        let mut stream = TcpStream::connect(&self.host)
+            .map_err(|err| format!("Failed to connect to {}: {err}", self.host))?;
+
+        let mut request = format!("GET {path} HTTP/1.0\r\nHost: {}\r\n", self.host);
+        if let Some(session) = &self.session {
+            request.push_str(&format!("Cookie: session={session}\r\n"));
+        }
+        request.push_str("\r\n");
+        stream
+            .write_all(request.as_bytes())
+            .map_err(|err| format!("Failed to send request to {}: {err}", self.host))?;
+
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .map_err(|err| format!("Failed to read response from {}: {err}", self.host))?;
+
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| format!("Malformed response from {}", self.host))?;
+        let status = head.lines().next().unwrap_or_default();
+        if status.split_ascii_whitespace().nth(1) != Some("200") {
+            return Err(format!("Failed to fetch {path}: `{status}`"));
+        }
+        Ok(body.to_owned())
+    }
+}
+
+/// The `data` directory of a day, which holds its inputs and recorded answers.
+pub fn data_dir(day: u8) -> PathBuf {
+    day_data_dir(&workspace_dir(), day)
+}
+
+fn day_data_dir(workspace: &Path, day: u8) -> PathBuf {
+    workspace.join(format!("day_{day}")).join("data")
+}
+
+fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+pub struct InputProvider {
+    workspace: PathBuf,
+    cache_dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl Default for InputProvider {
+    /// Reads the data directories of the workspace and caches under `target/inputs`, without
+    /// fetching anything.
+    fn default() -> Self {
+        let workspace = workspace_dir();
+        Self {
+            cache_dir: workspace.join("target").join("inputs"),
+            workspace,
+            fetcher: None,
+        }
+    }
+}
+
+impl InputProvider {
+    /// Looks for the `day_N/data` directories in `workspace` instead.
+    pub fn with_workspace(mut self, workspace: impl Into<PathBuf>) -> Self {
+        self.workspace = workspace.into();
+        self
+    }
+
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = cache_dir.into();
+        self
+    }
+
+    pub fn with_fetcher(mut self, fetcher: impl Fetcher + 'static) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day_{day}.txt"))
+    }
+
+    pub fn resolve(&self, day: u8, variant: &Variant) -> Result<String, String> {
+        match variant {
+            Variant::Real => self.real(day),
+            Variant::Small => read(day_data_dir(&self.workspace, day).join("input_small.txt")),
+            Variant::Path(path) => read(path),
+            Variant::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| format!("Failed to read stdin: {err}"))?;
+                Ok(input)
+            }
+        }
+    }
+
+    fn real(&self, day: u8) -> Result<String, String> {
+        let local = day_data_dir(&self.workspace, day).join("input.txt");
+        if local.exists() {
+            return read(local);
+        }
+        let cached = self.cache_path(day);
+        if cached.exists() {
+            return read(cached);
+        }
+
+        let fetcher = self.fetcher.as_ref().ok_or_else(|| {
+            format!(
+                "No input for day {day} at {} or {} and no fetcher configured",
+                local.display(),
+                cached.display()
+            )
+        })?;
+        let input = fetcher.fetch(day)?;
+        std::fs::create_dir_all(&self.cache_dir)
+            .map_err(|err| format!("Failed to create {}: {err}", self.cache_dir.display()))?;
+        std::fs::write(&cached, &input)
+            .map_err(|err| format!("Failed to write {}: {err}", cached.display()))?;
+        Ok(input)
+    }
+}
+
+fn read(path: impl AsRef<Path>) -> Result<String, String> {
+    let path = path.as_ref();
+    std::fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {err}", path.display()))
+}
@@ -0,0 +1,8 @@
+mod answer;
+pub mod input;
+mod parse_error;
+mod solution;
+
+pub use answer::Answer;
+pub use parse_error::{parse_lines, ParseError};
+pub use solution::{Part, Solution};
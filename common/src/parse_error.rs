@@ -0,0 +1,75 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// A malformed input, pointing at the offending spot.
+///
+/// Lines and columns start at 1, columns count characters. Parsers of a single line report line 1;
+/// whoever parses the surrounding input moves the error to the right line with [`Self::on_line`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The whole line the error was found on.
+    pub snippet: String,
+    /// What the parser was looking for, e.g. "a number".
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, snippet: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            snippet: snippet.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// An error about `token`, which has to be a slice of `snippet`.
+    pub fn at_token(snippet: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(snippet.as_ptr() as usize)
+            .filter(|&offset| offset <= snippet.len())
+            .expect("the token should be a slice of the snippet");
+        let column = snippet[..offset].chars().count() + 1;
+        Self::new(1, column, snippet, expected)
+    }
+
+    /// An error about something missing at the end of `snippet`.
+    pub fn at_end(snippet: &str, expected: impl Into<String>) -> Self {
+        Self::new(1, snippet.chars().count() + 1, snippet, expected)
+    }
+
+    /// Moves an error that was reported relative to a single line to line `line` of the input.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let gutter = self.line.to_string().len();
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^", column = self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every line of the input on its own, fixing up the line numbers of errors.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| line.parse().map_err(|err: ParseError| err.on_line(idx + 1)))
+        .collect()
+}
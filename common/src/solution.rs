@@ -0,0 +1,63 @@
+use std::fmt::{Display, Formatter};
+
+use crate::Answer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A day of the calendar: the input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+    type Err: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    /// Parses the example from the puzzle text.
+    ///
+    /// Some examples are solved with other parameters than the real input, like a smaller
+    /// grid, which the input itself doesn't tell. Those days parse the example with them here.
+    fn parse_example(input: &str) -> Result<Self::Input, Self::Err> {
+        Self::parse(input)
+    }
+
+    /// Parses the input and solves the given parts in order.
+    fn solve(input: &str, parts: &[Part]) -> Result<Vec<Answer>, String> {
+        let input = Self::parse(input).map_err(|err| format!("Failed to parse input: {err}"))?;
+        Ok(solve_parts::<Self>(&input, parts))
+    }
+
+    /// Parses the example from the puzzle text and solves the given parts in order.
+    fn solve_example(input: &str, parts: &[Part]) -> Result<Vec<Answer>, String> {
+        let input =
+            Self::parse_example(input).map_err(|err| format!("Failed to parse input: {err}"))?;
+        Ok(solve_parts::<Self>(&input, parts))
+    }
+}
+
+fn solve_parts<S: Solution + ?Sized>(input: &S::Input, parts: &[Part]) -> Vec<Answer> {
+    parts
+        .iter()
+        .map(|part| match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input),
+        })
+        .collect()
+}
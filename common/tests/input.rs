@@ -0,0 +1,144 @@
+use std::{
+    cell::Cell,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    path::PathBuf,
+    rc::Rc,
+    thread,
+};
+
+use common::input::{Fetcher, HttpFetcher, InputProvider, Variant};
+
+/// An empty directory in the system's temp directory, unique to the test.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-input-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("Should be able to create temp dir");
+    dir
+}
+
+/// Stands in for the puzzle server, counting how often it is asked.
+#[derive(Clone, Default)]
+struct StandIn {
+    requests: Rc<Cell<usize>>,
+}
+
+impl Fetcher for StandIn {
+    fn fetch(&self, day: u8) -> Result<String, String> {
+        self.requests.set(self.requests.get() + 1);
+        Ok(format!("input of day {day}\n"))
+    }
+}
+
+/// Serves a single request on a local port, answering with `status` and `body`.
+///
+/// Returns the base URL and a handle yielding the request line and headers.
+fn serve_once(
+    status: &'static str,
+    body: &'static str,
+) -> (String, thread::JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Should be able to bind");
+    let url = format!("http://{}/mirror", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("Should accept a connection");
+        let request: Vec<String> = BufReader::new(&stream)
+            .lines()
+            .map_while(Result::ok)
+            .take_while(|line| !line.is_empty())
+            .collect();
+        write!(
+            stream,
+            "HTTP/1.0 {status}\r\nContent-Type: text/plain\r\n\r\n{body}"
+        )
+        .expect("Should be able to respond");
+        request
+    });
+    (url, handle)
+}
+
+#[test]
+fn prefers_the_data_directory() {
+    let workspace = temp_dir("data");
+    let data = workspace.join("day_3").join("data");
+    std::fs::create_dir_all(&data).unwrap();
+    std::fs::write(data.join("input.txt"), "real").unwrap();
+    std::fs::write(data.join("input_small.txt"), "small").unwrap();
+
+    let stand_in = StandIn::default();
+    let inputs = InputProvider::default()
+        .with_workspace(&workspace)
+        .with_cache_dir(workspace.join("cache"))
+        .with_fetcher(stand_in.clone());
+
+    assert_eq!(inputs.resolve(3, &Variant::Real).unwrap(), "real");
+    assert_eq!(inputs.resolve(3, &Variant::Small).unwrap(), "small");
+    assert_eq!(
+        inputs
+            .resolve(3, &Variant::Path(data.join("input_small.txt")))
+            .unwrap(),
+        "small"
+    );
+    assert_eq!(stand_in.requests.get(), 0);
+}
+
+#[test]
+fn fetches_missing_inputs_once_and_caches_them() {
+    let workspace = temp_dir("cache");
+    let stand_in = StandIn::default();
+    let inputs = InputProvider::default()
+        .with_workspace(&workspace)
+        .with_cache_dir(workspace.join("cache"))
+        .with_fetcher(stand_in.clone());
+
+    assert_eq!(
+        inputs.resolve(7, &Variant::Real).unwrap(),
+        "input of day 7\n"
+    );
+    assert_eq!(
+        inputs.resolve(7, &Variant::Real).unwrap(),
+        "input of day 7\n"
+    );
+    assert_eq!(stand_in.requests.get(), 1);
+    assert_eq!(
+        std::fs::read_to_string(inputs.cache_path(7)).unwrap(),
+        "input of day 7\n"
+    );
+}
+
+#[test]
+fn fails_without_input_or_fetcher() {
+    let workspace = temp_dir("missing");
+    let inputs = InputProvider::default()
+        .with_workspace(&workspace)
+        .with_cache_dir(workspace.join("cache"));
+
+    let err = inputs.resolve(9, &Variant::Real).unwrap_err();
+    assert!(err.contains("no fetcher configured"), "{err}");
+    assert!(inputs.resolve(9, &Variant::Small).is_err());
+}
+
+#[test]
+fn http_fetcher_requests_the_input_with_the_session() {
+    let (url, server) = serve_once("200 OK", "1 2 3\n");
+    let fetcher = HttpFetcher::new(&url, Some("secret".to_owned())).unwrap();
+
+    assert_eq!(fetcher.fetch(11).unwrap(), "1 2 3\n");
+    let request = server.join().unwrap();
+    assert_eq!(request[0], "GET /mirror/2024/day/11/input HTTP/1.0");
+    assert!(request.contains(&"Cookie: session=secret".to_owned()));
+}
+
+#[test]
+fn http_fetcher_reports_failed_requests() {
+    let (url, server) = serve_once(
+        "404 Not Found",
+        "Please don't repeatedly request this endpoint",
+    );
+    let fetcher = HttpFetcher::new(&url, None).unwrap();
+
+    let err = fetcher.fetch(25).unwrap_err();
+    assert!(err.contains("404 Not Found"), "{err}");
+    server.join().unwrap();
+
+    assert!(HttpFetcher::new("https://adventofcode.com", None).is_err());
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use common::{Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct LocationLists {
+    left: Vec<i32>,
+    right: Vec<i32>,
+}
+
+fn parse_location(line: &str, token: Option<&str>) -> Result<i32, ParseError> {
+    let token = token.ok_or_else(|| ParseError::at_end(line, "a location ID"))?;
+    token
+        .parse()
+        .map_err(|_| ParseError::at_token(line, token, "a location ID"))
+}
+
+impl std::str::FromStr for LocationLists {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut left = vec![];
+        let mut right = vec![];
+        for (idx, line) in s.lines().enumerate() {
+            let mut split = line.split_ascii_whitespace();
+            let (l, r) = parse_location(line, split.next())
+                .and_then(|l| Ok((l, parse_location(line, split.next())?)))
+                .map_err(|err| err.on_line(idx + 1))?;
+            left.push(l);
+            right.push(r);
+        }
+        Ok(Self { left, right })
+    }
+}
+
+impl LocationLists {
+    fn total_distance(&self) -> i32 {
+        let mut left = self.left.clone();
+        let mut right = self.right.clone();
+        left.sort_unstable();
+        right.sort_unstable();
+
+        left.iter()
+            .zip(right.iter())
+            .map(|(l, r)| (l - r).abs())
+            .sum()
+    }
+
+    fn similarity_score(&self) -> i32 {
+        let mut counts = HashMap::new();
+        for &value in &self.right {
+            *counts.entry(value).or_insert(0) += 1;
+        }
+
+        self.left
+            .iter()
+            .map(|value| match counts.get(value) {
+                Some(&count) => *value * count,
+                None => 0,
+            })
+            .sum::<i32>()
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = LocationLists;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.total_distance().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.similarity_score().into()
+    }
+}
@@ -0,0 +1,92 @@
+use std::{collections::HashSet, str::FromStr};
+
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point};
+
+#[derive(Debug)]
+pub struct TopographicalMap {
+    map: Grid<i8>,
+}
+
+impl FromStr for TopographicalMap {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, |c| {
+            c.to_digit(10)
+                .map(|height| height as i8)
+                .ok_or("a height from 0 to 9")
+        })
+        .map(|map| Self { map })
+    }
+}
+
+impl TopographicalMap {
+    fn trailhead_sum(&self) -> (usize, usize) {
+        self.trailheads()
+            .into_iter()
+            .map(|trailhead| self.trails(vec![trailhead], 1))
+            .map(|trails| {
+                let score = trails
+                    .iter()
+                    .map(|trail| *trail.last().expect("trail should have at least one step"))
+                    .collect::<HashSet<_>>()
+                    .len();
+                // println!("start: {:?}, count: {:?}", trails[0][0], score);
+                (score, trails.len())
+            })
+            .fold((0, 0), |(acc_score, acc_len), (val_score, val_len)| {
+                (acc_score + val_score, acc_len + val_len)
+            })
+    }
+
+    fn trails(&self, trail: Vec<Point>, height: i8) -> Vec<Vec<Point>> {
+        if height == 10 {
+            return vec![trail];
+        }
+        self.get_surrounding_of_height(*trail.last().expect("trail shouldn't be empty"), height)
+            .map(|next_step| {
+                let mut trail_new = trail.clone();
+                trail_new.push(next_step);
+                trail_new
+            })
+            .flat_map(|trail| self.trails(trail, height + 1).into_iter())
+            .collect()
+    }
+
+    fn get_surrounding_of_height(
+        &self,
+        position: Point,
+        height: i8,
+    ) -> impl Iterator<Item = Point> + '_ {
+        self.map
+            .neighbours4(position)
+            .filter(move |&neighbour| self.map[neighbour] == height)
+    }
+
+    fn trailheads(&self) -> Vec<Point> {
+        self.map
+            .iter()
+            .filter(|(_, &height)| height == 0)
+            .map(|(position, _)| position)
+            .collect()
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = TopographicalMap;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.trailhead_sum().0.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.trailhead_sum().1.into()
+    }
+}
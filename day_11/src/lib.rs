@@ -0,0 +1,110 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{Answer, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Stones {
+    stones: Vec<String>,
+}
+
+impl FromStr for Stones {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = s.trim_end();
+        line.split_ascii_whitespace()
+            .map(|stone| {
+                stone
+                    .parse::<usize>()
+                    .map(|number| number.to_string())
+                    .map_err(|_| ParseError::at_token(line, stone, "a number"))
+            })
+            .collect::<Result<_, _>>()
+            .map(|stones| Self { stones })
+    }
+}
+
+impl Stones {
+    fn blink_stone(stone: &String) -> Vec<String> {
+        match stone {
+            _ if stone == "0" => vec!["1".to_owned()],
+            stone if stone.len() % 2 == 0 => {
+                let second_half = stone[stone.len() / 2..].trim_start_matches("0").to_owned();
+                vec![
+                    stone[..stone.len() / 2].to_owned(),
+                    if second_half.is_empty() {
+                        "0".to_owned()
+                    } else {
+                        second_half
+                    },
+                ]
+            }
+            _ => vec![times_2024(stone)],
+        }
+    }
+
+    fn blink_count(&self, blink_count: usize) -> usize {
+        let mut lookup = HashMap::new();
+        self.stones
+            .iter()
+            .map(|stone| self.stone_count(stone, blink_count, &mut lookup))
+            .sum()
+    }
+
+    fn stone_count(
+        &self,
+        stone: &String,
+        blink_count: usize,
+        lookup: &mut HashMap<(String, usize), usize>,
+    ) -> usize {
+        if blink_count == 0 {
+            1
+        } else if let Some(&count) = lookup.get(&(stone.clone(), blink_count)) {
+            count
+        } else {
+            let count = Self::blink_stone(stone)
+                .into_iter()
+                .map(|stone| self.stone_count(&stone, blink_count - 1, lookup))
+                .sum();
+            lookup.insert((stone.clone(), blink_count), count);
+            count
+        }
+    }
+}
+
+/// Multiplies the decimal digits of a stone by 2024, which doesn't overflow however many times
+/// a stone is multiplied.
+fn times_2024(stone: &str) -> String {
+    let mut digits = Vec::with_capacity(stone.len() + 4);
+    let mut carry = 0;
+    for digit in stone.bytes().rev() {
+        let product = u32::from(digit - b'0') * 2024 + carry;
+        digits.push(b'0' + (product % 10) as u8);
+        carry = product / 10;
+    }
+    while carry > 0 {
+        digits.push(b'0' + (carry % 10) as u8);
+        carry /= 10;
+    }
+    digits.reverse();
+    String::from_utf8(digits).expect("Digits should be ASCII")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Stones;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.blink_count(25).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.blink_count(75).into()
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point};
+
+/// A connected area of plots growing the same plant.
+#[derive(Debug, Clone)]
+pub struct Region {
+    plant: char,
+    area: usize,
+    perimeter: usize,
+    sides: usize,
+}
+
+impl Region {
+    fn price(&self) -> usize {
+        self.area * self.perimeter
+    }
+
+    fn discounted_price(&self) -> usize {
+        self.area * self.sides
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: area {}, perimeter {}, sides {}",
+            self.plant, self.area, self.perimeter, self.sides
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Garden {
+    map: Grid<char>,
+}
+
+impl FromStr for Garden {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(|map| Self { map })
+    }
+}
+
+impl Garden {
+    /// Flood fills the map, measuring every region on the way. Regions are listed in reading
+    /// order of their first plot.
+    pub fn regions(&self) -> Vec<Region> {
+        let map = &self.map;
+        let mut visited = Grid::new(map.width(), map.height(), false);
+        let mut regions = vec![];
+
+        for start in map.points() {
+            if visited[start] {
+                continue;
+            }
+            let plant = map[start];
+            let mut region = Region {
+                plant,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+            };
+
+            visited[start] = true;
+            let mut stack = vec![start];
+            while let Some(plot) = stack.pop() {
+                region.area += 1;
+                region.perimeter += Direction::ORTHOGONAL
+                    .into_iter()
+                    .filter(|&d| map.get(plot + d) != Some(&plant))
+                    .count();
+                region.sides += Self::corners(map, plot);
+
+                for neighbour in map.neighbours4(plot) {
+                    if map[neighbour] == plant && !visited[neighbour] {
+                        visited[neighbour] = true;
+                        stack.push(neighbour);
+                    }
+                }
+            }
+            regions.push(region);
+        }
+
+        regions
+    }
+
+    /// Every region has as many sides as corners, so counting the corners of each plot adds up
+    /// to the sides of its region.
+    fn corners(map: &Grid<char>, plot: Point) -> usize {
+        let plant = map[plot];
+        let same = |point: Point| map.get(point) == Some(&plant);
+
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter(|&d| {
+                let (a, b) = (plot + d, plot + d.turn_right());
+                let diagonal = plot + d.offset() + d.turn_right().offset();
+                let convex = !same(a) && !same(b);
+                let concave = same(a) && same(b) && !same(diagonal);
+                convex || concave
+            })
+            .count()
+    }
+
+    fn price(&self) -> usize {
+        self.regions().iter().map(Region::price).sum()
+    }
+
+    fn discounted_price(&self) -> usize {
+        self.regions().iter().map(Region::discounted_price).sum()
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Garden;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.price().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.discounted_price().into()
+    }
+}
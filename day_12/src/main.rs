@@ -1,3 +1,18 @@
+use common::{
+    input::{InputProvider, Variant},
+    Solution,
+};
+use day_12::Day12;
+
 fn main() {
-    println!("Hello, world!");
+    let contents = InputProvider::default()
+        .resolve(12, &Variant::Real)
+        .expect("Should be able to read input");
+
+    let garden = Day12::parse(&contents).expect("Should be able to parse garden");
+    for region in garden.regions() {
+        println!("{region}");
+    }
+    println!("Price of fencing: {}", Day12::part1(&garden));
+    println!("Price of fencing with discount: {}", Day12::part2(&garden));
 }
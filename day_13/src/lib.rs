@@ -0,0 +1,270 @@
+use std::str::FromStr;
+
+use common::{Answer, ParseError, Solution};
+
+const COST_A: i64 = 3;
+const COST_B: i64 = 1;
+
+/// How far the prize of part 2 is moved along both axes.
+pub const PRIZE_OFFSET: i64 = 10_000_000_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Vector {
+    x: i64,
+    y: i64,
+}
+
+impl Vector {
+    fn is_zero(&self) -> bool {
+        self.x == 0 && self.y == 0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    a: Vector,
+    b: Vector,
+    prize: Vector,
+}
+
+/// Parses `<label>: X<sign><x>, Y<sign><y>`, where `sign` is `+` for buttons and `=` for prizes.
+fn parse_vector(line: &str, label: &str, sign: char) -> Result<Vector, ParseError> {
+    let expected = format!("`{label}: X{sign}<x>, Y{sign}<y>`");
+    let rest = line
+        .strip_prefix(label)
+        .and_then(|rest| rest.strip_prefix(": X"))
+        .and_then(|rest| rest.strip_prefix(sign))
+        .ok_or_else(|| ParseError::new(1, 1, line, expected.as_str()))?;
+    let (x, y) = rest
+        .split_once(", Y")
+        .and_then(|(x, y)| Some((x, y.strip_prefix(sign)?)))
+        .ok_or_else(|| ParseError::at_token(line, rest, expected.as_str()))?;
+
+    let number = |token: &str| {
+        token
+            .parse()
+            .map_err(|_| ParseError::at_token(line, token, "a number"))
+    };
+    Ok(Vector {
+        x: number(x)?,
+        y: number(y)?,
+    })
+}
+
+impl FromStr for ClawMachine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<_> = s.lines().collect();
+        let vector = |idx: usize, label: &str, sign: char| {
+            let Some(line) = lines.get(idx) else {
+                let last = lines.last().copied().unwrap_or_default();
+                let expected = format!("a line `{label}: ...` below");
+                return Err(ParseError::at_end(last, expected).on_line(lines.len().max(1)));
+            };
+            parse_vector(line, label, sign).map_err(|err| err.on_line(idx + 1))
+        };
+        Ok(Self {
+            a: vector(0, "Button A", '+')?,
+            b: vector(1, "Button B", '+')?,
+            prize: vector(2, "Prize", '=')?,
+        })
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Solves `a * x + b * y = gcd(a, b)`, returning `(x, y)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128) {
+    if b == 0 {
+        (a.signum(), 0)
+    } else {
+        let (x, y) = extended_gcd(b, a % b);
+        (y, x - (a / b) * y)
+    }
+}
+
+fn floor_div(n: i128, d: i128) -> i128 {
+    let q = n / d;
+    if n % d != 0 && (n < 0) != (d < 0) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+fn ceil_div(n: i128, d: i128) -> i128 {
+    -floor_div(-n, d)
+}
+
+/// The lowest and highest `k` for which `start + k * step` lies within `min..=max`, or `None`
+/// if working them out overflows.
+fn step_range(
+    start: i128,
+    step: i128,
+    min: i128,
+    max: Option<i128>,
+) -> Option<(Option<i128>, Option<i128>)> {
+    let low = min.checked_sub(start)?;
+    let high = match max {
+        Some(max) => Some(max.checked_sub(start)?),
+        None => None,
+    };
+    Some(if step > 0 {
+        (
+            Some(ceil_div(low, step)),
+            high.map(|high| floor_div(high, step)),
+        )
+    } else {
+        (
+            high.map(|high| ceil_div(high, step)),
+            Some(floor_div(low, step)),
+        )
+    })
+}
+
+impl ClawMachine {
+    /// The fewest tokens needed to win the prize, if it can be won at all.
+    ///
+    /// Button presses are limited to `press_limit` per button if given. Everything is worked
+    /// out in `i128`, machines whose numbers overflow even that can't be won.
+    fn cheapest(&self, offset: i64, press_limit: Option<i64>) -> Option<i64> {
+        let prize = (
+            i128::from(self.prize.x) + i128::from(offset),
+            i128::from(self.prize.y) + i128::from(offset),
+        );
+        let (presses_a, presses_b) = self.presses(prize, press_limit.map(i128::from))?;
+        let cost = presses_a
+            .checked_mul(COST_A.into())?
+            .checked_add(presses_b.checked_mul(COST_B.into())?)?;
+        i64::try_from(cost).ok()
+    }
+
+    fn presses(&self, prize: (i128, i128), press_limit: Option<i128>) -> Option<(i128, i128)> {
+        let (a, b) = (self.a, self.b);
+        let (ax, ay, bx, by) = (a.x as i128, a.y as i128, b.x as i128, b.y as i128);
+        let determinant = (ax * by).checked_sub(ay * bx)?;
+        if determinant != 0 {
+            // Cramer's rule, the presses have to come out as whole non-negative numbers
+            let numerator_a = prize
+                .0
+                .checked_mul(by)?
+                .checked_sub(prize.1.checked_mul(bx)?)?;
+            let numerator_b = ax
+                .checked_mul(prize.1)?
+                .checked_sub(ay.checked_mul(prize.0)?)?;
+            if numerator_a % determinant != 0 || numerator_b % determinant != 0 {
+                return None;
+            }
+            let presses = (numerator_a / determinant, numerator_b / determinant);
+            let within = |n: i128| n >= 0 && press_limit.is_none_or(|limit| n <= limit);
+            return (within(presses.0) && within(presses.1)).then_some(presses);
+        }
+
+        self.collinear_presses(prize, press_limit)
+    }
+
+    /// Both buttons move along the same line, so there may be many ways to reach the prize. The
+    /// cheapest is found by walking the solutions of the linear Diophantine equation along one
+    /// axis to the end of the allowed range.
+    fn collinear_presses(&self, prize: (i128, i128), limit: Option<i128>) -> Option<(i128, i128)> {
+        let (a, b) = (self.a, self.b);
+        if a.is_zero() && b.is_zero() {
+            return (prize == (0, 0)).then_some((0, 0));
+        }
+        let direction = if a.is_zero() { b } else { a };
+        if prize.1.checked_mul(direction.x.into())? != prize.0.checked_mul(direction.y.into())? {
+            return None;
+        }
+
+        // pick an axis the buttons actually move along
+        let (u, v, t) = if a.x != 0 || b.x != 0 {
+            (a.x as i128, b.x as i128, prize.0)
+        } else {
+            (a.y as i128, b.y as i128, prize.1)
+        };
+        let within = |n: i128| n >= 0 && limit.is_none_or(|limit| n <= limit);
+
+        // a button that doesn't move the claw is never worth pressing
+        if u == 0 {
+            return (t % v == 0 && within(t / v)).then(|| (0, t / v));
+        }
+        if v == 0 {
+            return (t % u == 0 && within(t / u)).then(|| (t / u, 0));
+        }
+
+        let g = gcd(u, v);
+        if t % g != 0 {
+            return None;
+        }
+        let (x, y) = extended_gcd(u, v);
+        let (a0, b0) = (x.checked_mul(t / g)?, y.checked_mul(t / g)?);
+        // every solution is `(a0 + k * step_a, b0 - k * step_b)`
+        let (step_a, step_b) = (v / g, u / g);
+
+        let (low_a, high_a) = step_range(a0, step_a, 0, limit)?;
+        let (low_b, high_b) = step_range(b0, -step_b, 0, limit)?;
+        let low = low_a.into_iter().chain(low_b).max();
+        let high = high_a.into_iter().chain(high_b).min();
+
+        let slope = COST_A as i128 * step_a - COST_B as i128 * step_b;
+        let k = match (low, high) {
+            (Some(low), Some(high)) if low > high => return None,
+            (Some(low), _) if slope >= 0 => low,
+            (_, Some(high)) if slope <= 0 => high,
+            // the cost only grows towards the open end, so there is a closed end on the other one
+            _ => unreachable!("the presses of both buttons are bounded from below"),
+        };
+        Some((
+            a0.checked_add(k.checked_mul(step_a)?)?,
+            b0.checked_sub(k.checked_mul(step_b)?)?,
+        ))
+    }
+}
+
+/// The fewest tokens to win every prize that can be won, `Unsolved` if that doesn't fit an
+/// `i64`.
+fn total_cost(machines: &[ClawMachine], offset: i64, press_limit: Option<i64>) -> Answer {
+    machines
+        .iter()
+        .filter_map(|machine| machine.cheapest(offset, press_limit))
+        .try_fold(0i64, |total, cost| total.checked_add(cost))
+        .map_or(Answer::Unsolved, Answer::from)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<ClawMachine>;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        let mut machines = vec![];
+        let mut first_line = 1;
+        for block in input.split("\n\n") {
+            if !block.trim().is_empty() {
+                machines.push(
+                    block
+                        .parse()
+                        .map_err(|err: ParseError| err.on_line(first_line))?,
+                );
+            }
+            first_line += block.lines().count() + 1;
+        }
+        Ok(machines)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        total_cost(input, 0, Some(100))
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        total_cost(input, PRIZE_OFFSET, None)
+    }
+}
@@ -1,3 +1,18 @@
+use common::{
+    input::{InputProvider, Variant},
+    Solution,
+};
+use day_13::Day13;
+
 fn main() {
-    println!("Hello, world!");
+    let contents = InputProvider::default()
+        .resolve(13, &Variant::Real)
+        .expect("Should be able to read input");
+
+    let machines = Day13::parse(&contents).expect("Should be able to parse claw machines");
+    println!("Fewest tokens: {}", Day13::part1(&machines));
+    println!(
+        "Fewest tokens with corrected prizes: {}",
+        Day13::part2(&machines)
+    );
 }
@@ -0,0 +1,175 @@
+use std::{fmt::Write, str::FromStr};
+
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point};
+
+/// Size of the bathroom of the real input.
+pub const REAL_SIZE: (usize, usize) = (101, 103);
+/// Size of the bathroom of the example in the puzzle text.
+pub const EXAMPLE_SIZE: (usize, usize) = (11, 7);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Robot {
+    position: Point,
+    velocity: Point,
+}
+
+/// Parses `x,y` following `prefix`.
+fn parse_point<'a>(line: &str, token: &'a str, prefix: &str) -> Result<Point, ParseError> {
+    let expected = format!("`{prefix}<x>,<y>`");
+    let (x, y) = token
+        .strip_prefix(prefix)
+        .and_then(|rest| rest.split_once(','))
+        .ok_or_else(|| ParseError::at_token(line, token, expected))?;
+    let number = |n: &'a str| {
+        n.parse()
+            .map_err(|_| ParseError::at_token(line, n, "a number"))
+    };
+    Ok(Point::new(number(x)?, number(y)?))
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at_end(s, "` v=<x>,<y>`"))?;
+        Ok(Self {
+            position: parse_point(s, position, "p=")?,
+            velocity: parse_point(s, velocity, "v=")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bathroom {
+    robots: Vec<Robot>,
+    width: usize,
+    height: usize,
+}
+
+impl Bathroom {
+    /// Panics for a width or height of 0, robots can't wrap around in an empty bathroom.
+    pub fn new(robots: Vec<Robot>, width: usize, height: usize) -> Self {
+        assert!(
+            width > 0 && height > 0,
+            "The bathroom should be at least 1 tile wide and high, not {width}x{height}"
+        );
+        Self {
+            robots,
+            width,
+            height,
+        }
+    }
+
+    /// The same robots in a bathroom of another size.
+    pub fn with_size(self, (width, height): (usize, usize)) -> Self {
+        Self::new(self.robots, width, height)
+    }
+
+    /// Where the robots are after `seconds`, wrapping around the walls.
+    fn positions(&self, seconds: usize) -> impl Iterator<Item = Point> + '_ {
+        let (width, height) = (self.width as isize, self.height as isize);
+        self.robots.iter().map(move |robot| {
+            let moved = robot.position + robot.velocity * seconds as isize;
+            Point::new(moved.x.rem_euclid(width), moved.y.rem_euclid(height))
+        })
+    }
+
+    /// The product of the robot counts of the four quadrants. Robots exactly in the middle don't
+    /// count towards any quadrant.
+    fn safety_factor(&self, seconds: usize) -> usize {
+        let (mid_x, mid_y) = ((self.width / 2) as isize, (self.height / 2) as isize);
+        let mut quadrants = [0; 4];
+        for position in self.positions(seconds) {
+            if position.x == mid_x || position.y == mid_y {
+                continue;
+            }
+            let idx = usize::from(position.x > mid_x) + 2 * usize::from(position.y > mid_y);
+            quadrants[idx] += 1;
+        }
+        quadrants.iter().product()
+    }
+
+    /// Finds the second at which the robots form the Christmas tree.
+    ///
+    /// The tree clusters the robots, so the spread of the x coordinates is smallest at the tree,
+    /// and since they repeat every `width` seconds, the best offset in the first `width` seconds
+    /// is found independently of the best offset of the y coordinates. The second matching both
+    /// offsets follows from the Chinese remainder theorem, so there may be none for an empty
+    /// bathroom or one whose width and height aren't coprime.
+    pub fn find_tree(&self) -> Option<usize> {
+        let best_x = (0..self.width)
+            .min_by_key(|&seconds| variance(self.positions(seconds).map(|p| p.x)))?;
+        let best_y = (0..self.height)
+            .min_by_key(|&seconds| variance(self.positions(seconds).map(|p| p.y)))?;
+
+        (0..self.height)
+            .map(|n| best_x + n * self.width)
+            .find(|seconds| seconds % self.height == best_y)
+    }
+
+    /// Renders the bathroom after `seconds` like in the puzzle text: the number of robots on a
+    /// tile, or `.` for empty tiles.
+    pub fn frame(&self, seconds: usize) -> String {
+        let mut counts = Grid::new(self.width, self.height, 0);
+        for position in self.positions(seconds) {
+            counts[position] += 1;
+        }
+
+        let mut frame = String::new();
+        for row in counts.rows() {
+            for &count in row {
+                match count {
+                    0 => frame.push('.'),
+                    count => write!(frame, "{count}").expect("Writing to a string should not fail"),
+                }
+            }
+            frame.push('\n');
+        }
+        frame
+    }
+}
+
+/// Scaled variance, which avoids floating point numbers and still orders spreads correctly.
+fn variance(values: impl Iterator<Item = isize>) -> isize {
+    let values: Vec<_> = values.collect();
+    let n = values.len() as isize;
+    let sum: isize = values.iter().sum();
+    let squares: isize = values.iter().map(|v| v * v).sum();
+    n * squares - sum * sum
+}
+
+impl FromStr for Bathroom {
+    type Err = ParseError;
+
+    /// Robots in a bathroom of the real size, see [`Bathroom::with_size`] for the example.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (width, height) = REAL_SIZE;
+        Ok(Self::new(common::parse_lines(s)?, width, height))
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Bathroom;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input, Self::Err> {
+        Ok(Self::parse(input)?.with_size(EXAMPLE_SIZE))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.safety_factor(100).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.find_tree().map_or(Answer::Unsolved, Answer::from)
+    }
+}
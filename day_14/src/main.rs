@@ -1,3 +1,22 @@
+use common::{
+    input::{InputProvider, Variant},
+    Solution,
+};
+use day_14::Day14;
+
 fn main() {
-    println!("Hello, world!");
+    let contents = InputProvider::default()
+        .resolve(14, &Variant::Real)
+        .expect("Should be able to read input");
+
+    let bathroom = Day14::parse(&contents).expect("Should be able to parse robots");
+    println!("Safety factor: {}", Day14::part1(&bathroom));
+
+    match bathroom.find_tree() {
+        Some(seconds) => {
+            println!("Christmas tree after {seconds} seconds:");
+            print!("{}", bathroom.frame(seconds));
+        }
+        None => println!("The robots never form a Christmas tree"),
+    }
 }
@@ -0,0 +1,233 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Formatter, Write},
+    str::FromStr,
+};
+
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Tile {
+    Floor,
+    Wall,
+    Box,
+    BoxLeft,
+    BoxRight,
+    Robot,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(match self {
+            Self::Floor => '.',
+            Self::Wall => '#',
+            Self::Box => 'O',
+            Self::BoxLeft => '[',
+            Self::BoxRight => ']',
+            Self::Robot => '@',
+        })
+    }
+}
+
+struct ParseTileError;
+
+impl TryFrom<char> for Tile {
+    type Error = ParseTileError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        // only narrow maps are parsed, halves of boxes come from widening so they're never split
+        match value {
+            '.' => Ok(Self::Floor),
+            '#' => Ok(Self::Wall),
+            'O' => Ok(Self::Box),
+            '@' => Ok(Self::Robot),
+            _ => Err(ParseTileError),
+        }
+    }
+}
+
+impl Display for ParseTileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "one of `.`, `#`, `O` or `@`")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    /// The robot isn't part of the map, its tile is floor.
+    map: Grid<Tile>,
+    robot: Point,
+    moves: Vec<Direction>,
+}
+
+impl FromStr for Warehouse {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (map, moves) = s.split_once("\n\n").unwrap_or((s, ""));
+        let mut map: Grid<Tile> = map.parse()?;
+
+        let robots: Vec<_> = map
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Robot)
+            .map(|(position, _)| position)
+            .collect();
+        let robot = match robots[..] {
+            [robot] => robot,
+            _ => {
+                // point at the second robot if there is one
+                let (line, column) = robots
+                    .get(1)
+                    .map(|p| (p.y as usize + 1, p.x as usize + 1))
+                    .unwrap_or((1, 1));
+                let snippet = s.lines().nth(line - 1).unwrap_or_default();
+                let expected = "exactly one robot `@`";
+                return Err(ParseError::new(line, column, snippet, expected));
+            }
+        };
+        map[robot] = Tile::Floor;
+
+        let first_line = map.height() + 2;
+        let moves = moves
+            .lines()
+            .enumerate()
+            .flat_map(|(idx, line)| {
+                line.chars().enumerate().map(move |(column, c)| match c {
+                    '^' => Ok(Direction::Up),
+                    '>' => Ok(Direction::Right),
+                    'v' => Ok(Direction::Down),
+                    '<' => Ok(Direction::Left),
+                    _ => Err(ParseError::new(
+                        first_line + idx,
+                        column + 1,
+                        line,
+                        "one of `^`, `>`, `v` or `<`",
+                    )),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { map, robot, moves })
+    }
+}
+
+impl Warehouse {
+    /// The second warehouse, where everything except the robot is twice as wide.
+    pub fn widened(&self) -> Self {
+        let cells = self
+            .map
+            .rows()
+            .flat_map(|row| row.iter())
+            .flat_map(|tile| match tile {
+                Tile::Box => [Tile::BoxLeft, Tile::BoxRight],
+                &tile => [tile, tile],
+            })
+            .collect();
+        Self {
+            map: Grid::from_cells(self.map.width() * 2, self.map.height(), cells),
+            robot: Point::new(self.robot.x * 2, self.robot.y),
+            moves: self.moves.clone(),
+        }
+    }
+
+    /// Tries to move the robot, pushing every box in the way. Either all boxes move or, if any
+    /// of them is blocked by a wall, nothing does. The edge of the map blocks like a wall, even
+    /// if the map has no border of walls.
+    fn step(&mut self, direction: Direction) {
+        let mut pushed = vec![];
+        let mut seen = HashSet::new();
+        let mut frontier = vec![self.robot];
+
+        while let Some(position) = frontier.pop() {
+            let next = position + direction;
+            let mut parts = vec![next];
+            match self.map.get(next).copied().unwrap_or(Tile::Wall) {
+                Tile::Wall => return,
+                Tile::Floor | Tile::Robot => continue,
+                Tile::Box => {}
+                // a wide box moving up or down pushes whatever is in front of both halves
+                Tile::BoxLeft if direction.is_vertical() => parts.push(next + Direction::Right),
+                Tile::BoxRight if direction.is_vertical() => parts.push(next + Direction::Left),
+                Tile::BoxLeft | Tile::BoxRight => {}
+            }
+            for part in parts {
+                if seen.insert(part) {
+                    pushed.push(part);
+                    frontier.push(part);
+                }
+            }
+        }
+
+        let tiles: Vec<_> = pushed.iter().map(|&p| self.map[p]).collect();
+        for &position in &pushed {
+            self.map[position] = Tile::Floor;
+        }
+        for (position, tile) in pushed.into_iter().zip(tiles) {
+            self.map[position + direction] = tile;
+        }
+        self.robot += direction;
+    }
+
+    /// Executes the first `steps` moves.
+    pub fn run(&mut self, steps: usize) {
+        for idx in 0..steps.min(self.moves.len()) {
+            self.step(self.moves[idx]);
+        }
+    }
+
+    /// Renders the warehouse after the first `steps` moves.
+    pub fn render_after(&self, steps: usize) -> String {
+        let mut warehouse = self.clone();
+        warehouse.run(steps);
+        warehouse.to_string()
+    }
+
+    fn gps_sum(&self) -> usize {
+        self.map
+            .iter()
+            .filter(|(_, &tile)| matches!(tile, Tile::Box | Tile::BoxLeft))
+            .map(|(position, _)| (100 * position.y + position.x) as usize)
+            .sum()
+    }
+
+    fn gps_sum_after_moves(&self) -> usize {
+        let mut warehouse = self.clone();
+        warehouse.run(self.moves.len());
+        warehouse.gps_sum()
+    }
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (position, tile) in self.map.iter() {
+            if position.x == 0 && position.y != 0 {
+                writeln!(f)?;
+            }
+            if position == self.robot {
+                write!(f, "{}", Tile::Robot)?;
+            } else {
+                write!(f, "{}", tile)?;
+            }
+        }
+        writeln!(f)
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.gps_sum_after_moves().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.widened().gps_sum_after_moves().into()
+    }
+}
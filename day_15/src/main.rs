@@ -1,3 +1,18 @@
+use common::{
+    input::{InputProvider, Variant},
+    Solution,
+};
+use day_15::Day15;
+
 fn main() {
-    println!("Hello, world!");
+    let contents = InputProvider::default()
+        .resolve(15, &Variant::Real)
+        .expect("Should be able to read input");
+
+    let warehouse = Day15::parse(&contents).expect("Should be able to parse warehouse");
+    println!("Sum of GPS coordinates: {}", Day15::part1(&warehouse));
+    println!(
+        "Sum of GPS coordinates in the wide warehouse: {}",
+        Day15::part2(&warehouse)
+    );
 }
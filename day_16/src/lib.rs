@@ -0,0 +1,203 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    fmt::{Display, Formatter, Write},
+    str::FromStr,
+};
+
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point};
+
+const FORWARD_COST: usize = 1;
+const TURN_COST: usize = 1000;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Tile {
+    Floor,
+    Wall,
+    Start,
+    End,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(match self {
+            Self::Floor => '.',
+            Self::Wall => '#',
+            Self::Start => 'S',
+            Self::End => 'E',
+        })
+    }
+}
+
+struct ParseTileError;
+
+impl TryFrom<char> for Tile {
+    type Error = ParseTileError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Floor),
+            '#' => Ok(Self::Wall),
+            'S' => Ok(Self::Start),
+            'E' => Ok(Self::End),
+            _ => Err(ParseTileError),
+        }
+    }
+}
+
+impl Display for ParseTileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "one of `.`, `#`, `S` or `E`")
+    }
+}
+
+/// Where the reindeer is and where it is looking.
+type State = (Point, Direction);
+
+#[derive(Debug)]
+pub struct Maze {
+    map: Grid<Tile>,
+    start: Point,
+    end: Point,
+}
+
+impl FromStr for Maze {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map: Grid<Tile> = s.parse()?;
+        let find = |tile: Tile| {
+            map.position(|&t| t == tile).ok_or_else(|| {
+                let expected = format!("a tile `{tile}` somewhere in the maze");
+                ParseError::new(1, 1, s.lines().next().unwrap_or_default(), expected)
+            })
+        };
+        let (start, end) = (find(Tile::Start)?, find(Tile::End)?);
+        Ok(Self { map, start, end })
+    }
+}
+
+/// The result of searching the maze: the lowest score of every reachable state and the states
+/// each of them can be reached from on a cheapest path.
+struct Search {
+    scores: HashMap<State, usize>,
+    predecessors: HashMap<State, Vec<State>>,
+}
+
+impl Maze {
+    fn moves(&self, (position, facing): State) -> impl Iterator<Item = (State, usize)> + '_ {
+        let forward = position + facing;
+        let step = matches!(self.map.get(forward), Some(tile) if *tile != Tile::Wall)
+            .then_some(((forward, facing), FORWARD_COST));
+        let turns =
+            [facing.turn_left(), facing.turn_right()].map(|turned| ((position, turned), TURN_COST));
+        step.into_iter().chain(turns)
+    }
+
+    /// Dijkstra over (position, facing), starting east at the start tile.
+    fn search(&self) -> Search {
+        let start = (self.start, Direction::Right);
+        let mut scores = HashMap::from([(start, 0)]);
+        let mut predecessors: HashMap<State, Vec<State>> = HashMap::new();
+        let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+        while let Some(Reverse((score, state))) = queue.pop() {
+            if scores.get(&state).is_some_and(|&best| score > best) {
+                continue;
+            }
+            for (next, cost) in self.moves(state) {
+                let next_score = score + cost;
+                match scores.get(&next) {
+                    Some(&best) if next_score > best => {}
+                    Some(&best) if next_score == best => {
+                        predecessors.entry(next).or_default().push(state);
+                    }
+                    _ => {
+                        scores.insert(next, next_score);
+                        predecessors.insert(next, vec![state]);
+                        queue.push(Reverse((next_score, next)));
+                    }
+                }
+            }
+        }
+
+        Search {
+            scores,
+            predecessors,
+        }
+    }
+
+    fn lowest_score_in(&self, search: &Search) -> Option<usize> {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(|facing| search.scores.get(&(self.end, facing)).copied())
+            .min()
+    }
+
+    fn lowest_score(&self) -> Option<usize> {
+        self.lowest_score_in(&self.search())
+    }
+
+    /// Every tile that is part of at least one of the best paths.
+    pub fn best_path_tiles(&self) -> HashSet<Point> {
+        let search = self.search();
+        let Some(lowest) = self.lowest_score_in(&search) else {
+            return HashSet::new();
+        };
+
+        let mut stack: Vec<State> = Direction::ORTHOGONAL
+            .into_iter()
+            .map(|facing| (self.end, facing))
+            .filter(|state| search.scores.get(state) == Some(&lowest))
+            .collect();
+        let mut seen: HashSet<State> = stack.iter().copied().collect();
+        while let Some(state) = stack.pop() {
+            for &previous in search.predecessors.get(&state).into_iter().flatten() {
+                if seen.insert(previous) {
+                    stack.push(previous);
+                }
+            }
+        }
+
+        seen.into_iter().map(|(position, _)| position).collect()
+    }
+
+    /// The map with every tile on a best path marked as `O`.
+    pub fn render_best_paths(&self) -> String {
+        let tiles = self.best_path_tiles();
+        let mut rendered = String::new();
+        for (position, tile) in self.map.iter() {
+            if position.x == 0 && position.y != 0 {
+                rendered.push('\n');
+            }
+            if tiles.contains(&position) {
+                rendered.push('O');
+            } else {
+                write!(rendered, "{tile}").expect("Writing to a string should not fail");
+            }
+        }
+        rendered.push('\n');
+        rendered
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .lowest_score()
+            .map_or(Answer::Unsolved, |score| score.into())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.best_path_tiles().len().into()
+    }
+}
@@ -1,3 +1,16 @@
+use common::{
+    input::{InputProvider, Variant},
+    Solution,
+};
+use day_16::Day16;
+
 fn main() {
-    println!("Hello, world!");
+    let contents = InputProvider::default()
+        .resolve(16, &Variant::Real)
+        .expect("Should be able to read input");
+
+    let maze = Day16::parse(&contents).expect("Should be able to parse maze");
+    println!("Lowest score: {}", Day16::part1(&maze));
+    println!("Tiles on best paths: {}", Day16::part2(&maze));
+    print!("{}", maze.render_best_paths());
 }
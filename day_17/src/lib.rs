@@ -0,0 +1,275 @@
+use std::{
+    fmt::{Display, Formatter, Write},
+    str::FromStr,
+};
+
+use common::{Answer, ParseError, Solution};
+
+/// Instructions executed, by part 1 or by the whole search of part 2, before a program is
+/// taken to never halt.
+const MAX_STEPS: usize = 1 << 22;
+/// Values output by part 1 before a program is taken to output forever.
+const MAX_OUTPUT: usize = 1 << 16;
+
+/// Why a run of a program was cut short.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cutoff {
+    Steps,
+    Output,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    fn from_bits(bits: u8) -> Self {
+        match bits {
+            0 => Opcode::Adv,
+            1 => Opcode::Bxl,
+            2 => Opcode::Bst,
+            3 => Opcode::Jnz,
+            4 => Opcode::Bxc,
+            5 => Opcode::Out,
+            6 => Opcode::Bdv,
+            7 => Opcode::Cdv,
+            _ => unreachable!("opcodes have three bits"),
+        }
+    }
+
+    fn takes_combo_operand(&self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv
+        )
+    }
+}
+
+impl Display for Opcode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        })
+    }
+}
+
+/// Renders a combo operand: literals 0-3 or one of the registers.
+fn combo_name(operand: u8) -> String {
+    match operand {
+        0..=3 => operand.to_string(),
+        4 => "A".to_owned(),
+        5 => "B".to_owned(),
+        6 => "C".to_owned(),
+        _ => unreachable!("programs with reserved combo operands are rejected while parsing"),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    a: u64,
+    b: u64,
+    c: u64,
+    program: Vec<u8>,
+}
+
+fn parse_register(line: Option<(usize, &str)>, name: char) -> Result<u64, ParseError> {
+    let prefix = format!("Register {name}: ");
+    let (idx, line) = line.ok_or_else(|| ParseError::at_end("", format!("`{prefix}<value>`")))?;
+    let value = line
+        .strip_prefix(&prefix)
+        .ok_or_else(|| ParseError::new(1, 1, line, format!("`{prefix}<value>`")))?;
+    value
+        .parse()
+        .map_err(|_| ParseError::at_token(line, value, "a number"))
+        .map_err(|err| err.on_line(idx + 1))
+}
+
+impl FromStr for Computer {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate().filter(|(_, line)| !line.is_empty());
+        let a = parse_register(lines.next(), 'A')?;
+        let b = parse_register(lines.next(), 'B')?;
+        let c = parse_register(lines.next(), 'C')?;
+
+        let (idx, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::at_end("", "`Program: <instructions>`"))?;
+        let program = parse_program(line).map_err(|err| err.on_line(idx + 1))?;
+
+        Ok(Self { a, b, c, program })
+    }
+}
+
+fn parse_program(line: &str) -> Result<Vec<u8>, ParseError> {
+    let instructions = line
+        .strip_prefix("Program: ")
+        .ok_or_else(|| ParseError::new(1, 1, line, "`Program: <instructions>`"))?;
+    let tokens: Vec<_> = instructions.split(',').collect();
+    let program = tokens
+        .iter()
+        .map(|&token| match token.parse() {
+            Ok(bits @ 0..=7) => Ok(bits),
+            _ => Err(ParseError::at_token(line, token, "a 3-bit number")),
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    if program.len() % 2 != 0 {
+        return Err(ParseError::at_end(line, "an operand"));
+    }
+    for (idx, pair) in program.chunks(2).enumerate() {
+        if Opcode::from_bits(pair[0]).takes_combo_operand() && pair[1] == 7 {
+            let expected = "a combo operand from 0 to 6";
+            return Err(ParseError::at_token(line, tokens[2 * idx + 1], expected));
+        }
+    }
+    Ok(program)
+}
+
+impl Computer {
+    /// Runs the program with register A set to `a` until it halts, collecting its output.
+    ///
+    /// Every instruction uses up one of `steps`, and the run is cut short once they are used up
+    /// or once it would output more than `max_output` values.
+    fn run(&self, a: u64, steps: &mut usize, max_output: usize) -> Result<Vec<u8>, Cutoff> {
+        let (mut a, mut b, mut c) = (a, self.b, self.c);
+        let mut output = vec![];
+        let mut ip = 0;
+
+        while ip + 1 < self.program.len() {
+            *steps = steps.checked_sub(1).ok_or(Cutoff::Steps)?;
+            let opcode = Opcode::from_bits(self.program[ip]);
+            let literal = self.program[ip + 1] as u64;
+            let combo = match literal {
+                0..=3 => literal,
+                4 => a,
+                5 => b,
+                6 => c,
+                _ => 0, // only ever read for instructions taking a literal operand
+            };
+            ip += 2;
+
+            // shifting by 64 or more bits leaves nothing
+            let shifted = |a: u64| {
+                a.checked_shr(combo.try_into().unwrap_or(u32::MAX))
+                    .unwrap_or(0)
+            };
+            match opcode {
+                Opcode::Adv => a = shifted(a),
+                Opcode::Bxl => b ^= literal,
+                Opcode::Bst => b = combo % 8,
+                Opcode::Jnz if a != 0 => ip = literal as usize,
+                Opcode::Jnz => {}
+                Opcode::Bxc => b ^= c,
+                Opcode::Out if output.len() == max_output => return Err(Cutoff::Output),
+                Opcode::Out => output.push((combo % 8) as u8),
+                Opcode::Bdv => b = shifted(a),
+                Opcode::Cdv => c = shifted(a),
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// The program in readable form, one instruction per line.
+    pub fn disassemble(&self) -> String {
+        let mut listing = String::new();
+        for (idx, pair) in self.program.chunks(2).enumerate() {
+            let opcode = Opcode::from_bits(pair[0]);
+            let operand = pair[1];
+            let effect = match opcode {
+                Opcode::Adv => format!("A = A >> {}", combo_name(operand)),
+                Opcode::Bxl => format!("B = B ^ {operand}"),
+                Opcode::Bst => format!("B = {} % 8", combo_name(operand)),
+                Opcode::Jnz => format!("if A != 0 jump to {operand}"),
+                Opcode::Bxc => "B = B ^ C".to_owned(),
+                Opcode::Out => format!("output {} % 8", combo_name(operand)),
+                Opcode::Bdv => format!("B = A >> {}", combo_name(operand)),
+                Opcode::Cdv => format!("C = A >> {}", combo_name(operand)),
+            };
+            writeln!(listing, "{:>2}: {opcode} {operand}  ; {effect}", idx * 2)
+                .expect("Writing to a string should not fail");
+        }
+        listing
+    }
+
+    /// The output of the program, or `None` if it doesn't halt within the limits.
+    fn output(&self) -> Option<String> {
+        let mut steps = MAX_STEPS;
+        let output = self.run(self.a, &mut steps, MAX_OUTPUT).ok()?;
+        Some(
+            output
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+
+    /// The lowest value of register A for which the program outputs itself.
+    ///
+    /// Programs like these loop until A is zero, consume the lowest three bits of A per output
+    /// and shift them out at the end of the loop. So the last output only depends on the highest
+    /// octal digit of A, the second to last output on the two highest digits and so on. A is
+    /// built up digit by digit, keeping every prefix that produces the matching tail of the
+    /// program. Programs that run too long give up the search, see [`MAX_STEPS`].
+    fn find_quine(&self) -> Option<u64> {
+        let mut steps = MAX_STEPS;
+        let mut candidates = vec![0u64];
+        for len in 1..=self.program.len() {
+            let tail = &self.program[self.program.len() - len..];
+            let mut matching = vec![];
+            for prefix in candidates {
+                // another digit doesn't fit into A
+                let Some(prefix) = prefix.checked_mul(8) else {
+                    continue;
+                };
+                for a in (0..8).map(|digit| prefix + digit) {
+                    match self.run(a, &mut steps, tail.len()) {
+                        Ok(output) if output == tail => matching.push(a),
+                        Ok(_) | Err(Cutoff::Output) => {}
+                        // the search gives up on programs that don't halt
+                        Err(Cutoff::Steps) => return None,
+                    }
+                }
+            }
+            candidates = matching;
+        }
+        candidates.into_iter().filter(|&a| a != 0).min()
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Computer;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.output().map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.find_quine().map_or(Answer::Unsolved, |a| a.into())
+    }
+}
@@ -1,3 +1,16 @@
+use common::{
+    input::{InputProvider, Variant},
+    Solution,
+};
+use day_17::Day17;
+
 fn main() {
-    println!("Hello, world!");
+    let contents = InputProvider::default()
+        .resolve(17, &Variant::Real)
+        .expect("Should be able to read input");
+
+    let computer = Day17::parse(&contents).expect("Should be able to parse computer");
+    print!("{}", computer.disassemble());
+    println!("Output: {}", Day17::part1(&computer));
+    println!("Lowest A printing the program: {}", Day17::part2(&computer));
 }
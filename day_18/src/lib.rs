@@ -0,0 +1,150 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point};
+
+/// Side length of the memory space and the number of fallen bytes for part 1 of the real input.
+pub const REAL_PARAMS: (usize, usize) = (71, 1024);
+/// Side length of the memory space and the number of fallen bytes for part 1 of the example.
+pub const EXAMPLE_PARAMS: (usize, usize) = (7, 12);
+
+#[derive(Debug, Clone)]
+pub struct MemorySpace {
+    size: usize,
+    fallen: usize,
+    bytes: Vec<Point>,
+}
+
+fn parse_byte(line: &str) -> Result<Point, ParseError> {
+    let (x, y) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::at_end(line, "`,<y>`"))?;
+    let coordinate = |token: &str| match token.parse() {
+        Ok(value) if value >= 0 => Ok(value),
+        _ => Err(ParseError::at_token(line, token, "a coordinate")),
+    };
+    Ok(Point::new(coordinate(x)?, coordinate(y)?))
+}
+
+impl FromStr for MemorySpace {
+    type Err = ParseError;
+
+    /// Bytes falling into a memory space with the parameters of the real input, see
+    /// [`MemorySpace::with_params`] for the example.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| parse_byte(line).map_err(|err| err.on_line(idx + 1)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (size, fallen) = REAL_PARAMS;
+        Ok(Self::new(bytes, size, fallen))
+    }
+}
+
+impl MemorySpace {
+    /// Panics for a side length of 0, which leaves no room for the start and the exit.
+    pub fn new(bytes: Vec<Point>, size: usize, fallen: usize) -> Self {
+        assert!(
+            size > 0,
+            "The memory space should have a side length of at least 1"
+        );
+        Self {
+            size,
+            fallen,
+            bytes,
+        }
+    }
+
+    /// The same bytes falling into a memory space of another side length, with another number
+    /// of them fallen for part 1.
+    pub fn with_params(self, (size, fallen): (usize, usize)) -> Self {
+        Self::new(self.bytes, size, fallen)
+    }
+
+    fn corrupted(&self, fallen: usize) -> Grid<bool> {
+        let mut corrupted = Grid::new(self.size, self.size, false);
+        for &byte in self.bytes.iter().take(fallen) {
+            if let Some(cell) = corrupted.get_mut(byte) {
+                *cell = true;
+            }
+        }
+        corrupted
+    }
+
+    /// Breadth first search from the top left to the bottom right corner after `fallen` bytes.
+    pub fn shortest_path(&self, fallen: usize) -> Option<usize> {
+        let corrupted = self.corrupted(fallen);
+        let start = Point::new(0, 0);
+        let exit = Point::new(self.size as isize - 1, self.size as isize - 1);
+        if corrupted[start] {
+            return None;
+        }
+
+        let mut steps = Grid::new(self.size, self.size, None);
+        steps[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(position) = queue.pop_front() {
+            let step = steps[position].expect("queued positions have been reached");
+            if position == exit {
+                return Some(step);
+            }
+            for neighbour in corrupted.neighbours4(position) {
+                if !corrupted[neighbour] && steps[neighbour].is_none() {
+                    steps[neighbour] = Some(step + 1);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        None
+    }
+
+    /// The first byte after which the exit can't be reached anymore.
+    ///
+    /// Whether the exit is reachable only ever changes once, so the number of fallen bytes is
+    /// binary searched.
+    pub fn first_blocking_byte(&self) -> Option<Point> {
+        let (mut reachable, mut blocked) = (0, self.bytes.len());
+        if self.shortest_path(blocked).is_some() {
+            return None;
+        }
+        while blocked - reachable > 1 {
+            let mid = (reachable + blocked) / 2;
+            if self.shortest_path(mid).is_some() {
+                reachable = mid;
+            } else {
+                blocked = mid;
+            }
+        }
+        Some(self.bytes[blocked - 1])
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = MemorySpace;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input, Self::Err> {
+        Ok(Self::parse(input)?.with_params(EXAMPLE_PARAMS))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .shortest_path(input.fallen)
+            .map_or(Answer::Unsolved, |steps| steps.into())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .first_blocking_byte()
+            .map_or(Answer::Unsolved, |byte| {
+                format!("{},{}", byte.x, byte.y).into()
+            })
+    }
+}
@@ -1,3 +1,15 @@
+use common::{
+    input::{InputProvider, Variant},
+    Solution,
+};
+use day_18::Day18;
+
 fn main() {
-    println!("Hello, world!");
+    let contents = InputProvider::default()
+        .resolve(18, &Variant::Real)
+        .expect("Should be able to read input");
+
+    let memory_space = Day18::parse(&contents).expect("Should be able to parse bytes");
+    println!("Fewest steps to the exit: {}", Day18::part1(&memory_space));
+    println!("First blocking byte: {}", Day18::part2(&memory_space));
 }
@@ -0,0 +1,101 @@
+use std::{collections::HashSet, str::FromStr};
+
+use common::{Answer, ParseError, Solution};
+
+const COLOURS: &str = "wubrg";
+
+#[derive(Debug, Clone)]
+pub struct TowelSet {
+    patterns: HashSet<String>,
+    longest_pattern: usize,
+    designs: Vec<String>,
+}
+
+/// Checks that `token` only consists of stripe colours.
+fn parse_stripes(line: &str, token: &str) -> Result<String, ParseError> {
+    match token.char_indices().find(|&(_, c)| !COLOURS.contains(c)) {
+        None if !token.is_empty() => Ok(token.to_owned()),
+        None => Err(ParseError::at_token(line, token, "a stripe colour")),
+        Some((idx, _)) => Err(ParseError::at_token(
+            line,
+            &token[idx..],
+            "one of `w`, `u`, `b`, `r` or `g`",
+        )),
+    }
+}
+
+impl FromStr for TowelSet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate();
+        let first = lines.next().map_or("", |(_, line)| line);
+        let patterns = first
+            .split(", ")
+            .map(|pattern| parse_stripes(first, pattern))
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        let designs = lines
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(idx, line)| parse_stripes(line, line).map_err(|err| err.on_line(idx + 1)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            longest_pattern: patterns.iter().map(String::len).max().unwrap_or(0),
+            patterns,
+            designs,
+        })
+    }
+}
+
+impl TowelSet {
+    /// The number of ways to arrange towels into the design.
+    ///
+    /// `ways[i]` counts the arrangements of the design from stripe `i` on, filled in from the end
+    /// of the design, so every suffix is only solved once.
+    fn arrangements(&self, design: &str) -> u64 {
+        let mut ways = vec![0; design.len() + 1];
+        ways[design.len()] = 1;
+        for start in (0..design.len()).rev() {
+            let longest = self.longest_pattern.min(design.len() - start);
+            ways[start] = (1..=longest)
+                .filter(|len| self.patterns.contains(&design[start..start + len]))
+                .map(|len| ways[start + len])
+                .sum();
+        }
+        ways[0]
+    }
+
+    fn possible_designs(&self) -> usize {
+        self.designs
+            .iter()
+            .filter(|design| self.arrangements(design) > 0)
+            .count()
+    }
+
+    fn total_arrangements(&self) -> u64 {
+        self.designs
+            .iter()
+            .map(|design| self.arrangements(design))
+            .sum()
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = TowelSet;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.possible_designs().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.total_arrangements().into()
+    }
+}
@@ -1,3 +1,15 @@
+use common::{
+    input::{InputProvider, Variant},
+    Solution,
+};
+use day_19::Day19;
+
 fn main() {
-    println!("Hello, world!");
+    let contents = InputProvider::default()
+        .resolve(19, &Variant::Real)
+        .expect("Should be able to read input");
+
+    let towels = Day19::parse(&contents).expect("Should be able to parse towels");
+    println!("Possible designs: {}", Day19::part1(&towels));
+    println!("Ways to arrange the designs: {}", Day19::part2(&towels));
 }
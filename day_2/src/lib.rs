@@ -0,0 +1,91 @@
+use std::str::FromStr;
+
+use common::{parse_lines, Answer, ParseError, Solution};
+
+pub struct Report {
+    levels: Vec<i32>,
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let levels: Vec<_> = s
+            .split_ascii_whitespace()
+            .map(|level| {
+                level
+                    .parse()
+                    .map_err(|_| ParseError::at_token(s, level, "a level"))
+            })
+            .collect::<Result<_, _>>()?;
+        if levels.len() < 2 {
+            return Err(ParseError::at_end(s, "at least two levels"));
+        }
+        Ok(Self { levels })
+    }
+}
+
+impl Report {
+    fn is_stable(&self) -> bool {
+        // parsed reports have two levels at least, but dampening a report of two leaves one
+        let [first, second, ..] = self.levels[..] else {
+            return true;
+        };
+        let is_increasing = second > first;
+        self.levels.windows(2).all(|window| {
+            let left = window[0];
+            let right = window[1];
+
+            let diff = (left - right).abs();
+            if !(1..=3).contains(&diff) {
+                return false;
+            }
+
+            if is_increasing {
+                if left > right {
+                    return false;
+                }
+            } else {
+                if right > left {
+                    return false;
+                }
+            }
+            true
+        })
+    }
+
+    fn is_stable_dampened(&self) -> bool {
+        (0..self.levels.len())
+            .map(|idx_skip| {
+                self.levels
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, &v)| if i == idx_skip { None } else { Some(v) })
+                    .collect()
+            })
+            .any(|levels| Self { levels }.is_stable())
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Report>;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().filter(|r| r.is_stable()).count().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .filter(|r| r.is_stable_dampened())
+            .count()
+            .into()
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Write},
+    str::FromStr,
+};
+
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point};
+
+/// The cheats of the real input have to save at least this many picoseconds.
+pub const REAL_MIN_SAVING: usize = 100;
+/// The example in the puzzle text lists the cheats saving at least this many picoseconds.
+pub const EXAMPLE_MIN_SAVING: usize = 50;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Tile {
+    Track,
+    Wall,
+    Start,
+    End,
+}
+
+struct ParseTileError;
+
+impl TryFrom<char> for Tile {
+    type Error = ParseTileError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Track),
+            '#' => Ok(Self::Wall),
+            'S' => Ok(Self::Start),
+            'E' => Ok(Self::End),
+            _ => Err(ParseTileError),
+        }
+    }
+}
+
+impl Display for ParseTileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "one of `.`, `#`, `S` or `E`")
+    }
+}
+
+#[derive(Debug)]
+pub struct Racetrack {
+    /// Picoseconds from the start for every tile of the track.
+    distances: Grid<Option<usize>>,
+    /// The track in the order it is raced.
+    track: Vec<Point>,
+    min_saving: usize,
+}
+
+impl FromStr for Racetrack {
+    type Err = ParseError;
+
+    /// A racetrack whose cheats have to save as much as the real input's, see
+    /// [`Racetrack::with_min_saving`] for the example.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map: Grid<Tile> = s.parse()?;
+        let first_line = s.lines().next().unwrap_or_default();
+        let start = map
+            .position(|&tile| tile == Tile::Start)
+            .ok_or_else(|| ParseError::new(1, 1, first_line, "a start `S` somewhere on the map"))?;
+
+        // there is only a single track, so it is followed without any search
+        let mut distances = Grid::new(map.width(), map.height(), None);
+        let mut track = vec![start];
+        distances[start] = Some(0);
+        let mut position = start;
+        while map[position] != Tile::End {
+            let next = map
+                .neighbours4(position)
+                .find(|&p| map[p] != Tile::Wall && distances[p].is_none())
+                .ok_or_else(|| {
+                    let line = s.lines().nth(position.y as usize).unwrap_or_default();
+                    let (row, column) = (position.y as usize + 1, position.x as usize + 1);
+                    ParseError::new(row, column, line, "the track to continue to the end `E`")
+                })?;
+            distances[next] = Some(track.len());
+            track.push(next);
+            position = next;
+        }
+
+        Ok(Self {
+            distances,
+            track,
+            min_saving: REAL_MIN_SAVING,
+        })
+    }
+}
+
+impl Racetrack {
+    /// The same racetrack with the cheats of both parts having to save at least `min_saving`
+    /// picoseconds.
+    pub fn with_min_saving(self, min_saving: usize) -> Self {
+        Self { min_saving, ..self }
+    }
+
+    /// The picoseconds saved by every cheat of at most `max_cheat` picoseconds that saves any
+    /// time at all.
+    ///
+    /// A cheat starts on the track and ends on the track at most `max_cheat` tiles away, so every
+    /// tile within that distance of every tile of the track is checked.
+    fn savings(&self, max_cheat: usize) -> impl Iterator<Item = usize> + '_ {
+        let radius = max_cheat as isize;
+        self.track
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, &start)| {
+                (-radius..=radius).flat_map(move |dy| {
+                    let width = radius - dy.abs();
+                    (-width..=width).filter_map(move |dx| {
+                        let end = start + Point::new(dx, dy);
+                        let to = self.distances.get(end).copied().flatten()?;
+                        let cheat = start.manhattan_distance(end);
+                        (to > from + cheat).then(|| to - from - cheat)
+                    })
+                })
+            })
+    }
+
+    /// The number of cheats saving at least `min_saving` picoseconds.
+    pub fn count_cheats(&self, max_cheat: usize, min_saving: usize) -> usize {
+        self.savings(max_cheat)
+            .filter(|&saving| saving >= min_saving)
+            .count()
+    }
+
+    /// How many cheats save how many picoseconds.
+    pub fn savings_histogram(&self, max_cheat: usize) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for saving in self.savings(max_cheat) {
+            *histogram.entry(saving).or_default() += 1;
+        }
+        histogram
+    }
+
+    /// The histogram of cheats saving at least `min_saving` picoseconds, worded like the listing
+    /// in the puzzle text.
+    pub fn savings_listing(&self, max_cheat: usize, min_saving: usize) -> String {
+        let mut listing = String::new();
+        for (saving, count) in self.savings_histogram(max_cheat).range(min_saving..) {
+            match count {
+                1 => writeln!(
+                    listing,
+                    "There is one cheat that saves {saving} picoseconds."
+                ),
+                _ => writeln!(
+                    listing,
+                    "There are {count} cheats that save {saving} picoseconds."
+                ),
+            }
+            .expect("Writing to a string should not fail");
+        }
+        listing
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Racetrack;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input, Self::Err> {
+        Ok(Self::parse(input)?.with_min_saving(EXAMPLE_MIN_SAVING))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.count_cheats(2, input.min_saving).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.count_cheats(20, input.min_saving).into()
+    }
+}
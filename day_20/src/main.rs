@@ -1,3 +1,15 @@
+use common::{
+    input::{InputProvider, Variant},
+    Solution,
+};
+use day_20::Day20;
+
 fn main() {
-    println!("Hello, world!");
+    let contents = InputProvider::default()
+        .resolve(20, &Variant::Real)
+        .expect("Should be able to read input");
+
+    let racetrack = Day20::parse(&contents).expect("Should be able to parse racetrack");
+    println!("Cheats of 2 picoseconds: {}", Day20::part1(&racetrack));
+    println!("Cheats of 20 picoseconds: {}", Day20::part2(&racetrack));
 }
@@ -0,0 +1,45 @@
+use common::Solution;
+use day_20::{Day20, Racetrack};
+
+fn example() -> Racetrack {
+    Day20::parse_example(include_str!("../data/input_small.txt")).expect("Example should parse")
+}
+
+#[test]
+fn lists_every_cheat_of_two_picoseconds() {
+    assert_eq!(
+        example().savings_listing(2, 1),
+        "There are 14 cheats that save 2 picoseconds.\n\
+         There are 14 cheats that save 4 picoseconds.\n\
+         There are 2 cheats that save 6 picoseconds.\n\
+         There are 4 cheats that save 8 picoseconds.\n\
+         There are 2 cheats that save 10 picoseconds.\n\
+         There are 3 cheats that save 12 picoseconds.\n\
+         There is one cheat that saves 20 picoseconds.\n\
+         There is one cheat that saves 36 picoseconds.\n\
+         There is one cheat that saves 38 picoseconds.\n\
+         There is one cheat that saves 40 picoseconds.\n\
+         There is one cheat that saves 64 picoseconds.\n"
+    );
+}
+
+#[test]
+fn lists_cheats_of_twenty_picoseconds_saving_at_least_fifty() {
+    assert_eq!(
+        example().savings_listing(20, 50),
+        "There are 32 cheats that save 50 picoseconds.\n\
+         There are 31 cheats that save 52 picoseconds.\n\
+         There are 29 cheats that save 54 picoseconds.\n\
+         There are 39 cheats that save 56 picoseconds.\n\
+         There are 25 cheats that save 58 picoseconds.\n\
+         There are 23 cheats that save 60 picoseconds.\n\
+         There are 20 cheats that save 62 picoseconds.\n\
+         There are 19 cheats that save 64 picoseconds.\n\
+         There are 12 cheats that save 66 picoseconds.\n\
+         There are 14 cheats that save 68 picoseconds.\n\
+         There are 12 cheats that save 70 picoseconds.\n\
+         There are 22 cheats that save 72 picoseconds.\n\
+         There are 4 cheats that save 74 picoseconds.\n\
+         There are 3 cheats that save 76 picoseconds.\n"
+    );
+}
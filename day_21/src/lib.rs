@@ -0,0 +1,192 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{parse_lines, Answer, ParseError, Solution};
+use grid::Point;
+
+/// A keypad: the position of every key and of the gap no robot arm may ever point at.
+#[derive(Debug, Clone)]
+pub struct Keypad {
+    keys: HashMap<char, Point>,
+    gap: Point,
+}
+
+impl Keypad {
+    /// Builds a keypad from its rows, with a space marking the gap.
+    fn from_rows(rows: &[&str]) -> Self {
+        let mut keys = HashMap::new();
+        let mut gap = None;
+        for (y, row) in rows.iter().enumerate() {
+            for (x, key) in row.chars().enumerate() {
+                let position = Point::new(x as isize, y as isize);
+                match key {
+                    ' ' => gap = Some(position),
+                    _ => {
+                        keys.insert(key, position);
+                    }
+                }
+            }
+        }
+        Self {
+            keys,
+            gap: gap.expect("every keypad has a gap"),
+        }
+    }
+
+    /// The keypad on the door.
+    pub fn numeric() -> Self {
+        Self::from_rows(&["789", "456", "123", " 0A"])
+    }
+
+    /// The keypad the robots are controlled with.
+    pub fn directional() -> Self {
+        Self::from_rows(&[" ^A", "<v>"])
+    }
+
+    /// The presses on a directional keypad that move the arm from `from` to `to` and press it.
+    ///
+    /// Changing direction only ever costs presses further up the chain, so only the two paths
+    /// that make a single turn are worth considering: horizontal first or vertical first, unless
+    /// that passes over the gap.
+    fn paths(&self, from: char, to: char) -> Vec<String> {
+        let (start, end) = (self.keys[&from], self.keys[&to]);
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        let horizontal = if dx < 0 { "<" } else { ">" }.repeat(dx.unsigned_abs());
+        let vertical = if dy < 0 { "^" } else { "v" }.repeat(dy.unsigned_abs());
+
+        let mut paths = vec![];
+        if Point::new(end.x, start.y) != self.gap {
+            paths.push(format!("{horizontal}{vertical}A"));
+        }
+        if Point::new(start.x, end.y) != self.gap {
+            paths.push(format!("{vertical}{horizontal}A"));
+        }
+        paths.dedup();
+        paths
+    }
+}
+
+/// Counts the presses needed on the outermost keypad, remembering the cost of every move of a
+/// directional keypad at every depth of the chain.
+struct PressCounter {
+    directional: Keypad,
+    memo: HashMap<(char, char, usize), u64>,
+}
+
+impl PressCounter {
+    fn new() -> Self {
+        Self {
+            directional: Keypad::directional(),
+            memo: HashMap::new(),
+        }
+    }
+
+    /// The presses needed to type `presses` on a directional keypad with `depth` more directional
+    /// keypads up the chain, the last of them operated by hand. Every arm starts at and returns
+    /// to `A`.
+    fn sequence_cost(&mut self, presses: &str, depth: usize) -> u64 {
+        let mut from = 'A';
+        let mut cost = 0;
+        for to in presses.chars() {
+            cost += self.move_cost(from, to, depth);
+            from = to;
+        }
+        cost
+    }
+
+    /// The presses needed to move the arm from `from` to `to` and press it.
+    fn move_cost(&mut self, from: char, to: char, depth: usize) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        if let Some(&cost) = self.memo.get(&(from, to, depth)) {
+            return cost;
+        }
+        let cost = self
+            .directional
+            .paths(from, to)
+            .iter()
+            .map(|path| self.sequence_cost(path, depth - 1))
+            .min()
+            .expect("every key can be reached");
+        self.memo.insert((from, to, depth), cost);
+        cost
+    }
+
+    /// The presses needed to type `code` on `keypad` through `robots` directional keypads
+    /// operated by robots, plus the one operated by hand.
+    fn code_cost(&mut self, keypad: &Keypad, code: &str, robots: usize) -> u64 {
+        let mut from = 'A';
+        let mut cost = 0;
+        for to in code.chars() {
+            cost += keypad
+                .paths(from, to)
+                .iter()
+                .map(|path| self.sequence_cost(path, robots))
+                .min()
+                .expect("every key can be reached");
+            from = to;
+        }
+        cost
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Code {
+    keys: String,
+    value: u64,
+}
+
+impl FromStr for Code {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((idx, _)) = s
+            .char_indices()
+            .find(|&(_, c)| !c.is_ascii_digit() && c != 'A')
+        {
+            return Err(ParseError::at_token(s, &s[idx..], "a digit or `A`"));
+        }
+        let digits = s
+            .strip_suffix('A')
+            .ok_or_else(|| ParseError::at_end(s, "`A`"))?;
+        if let Some(idx) = digits.find('A') {
+            return Err(ParseError::at_token(s, &digits[idx..], "a digit"));
+        }
+        let value = digits
+            .parse()
+            .map_err(|_| ParseError::at_token(s, digits, "a number"))?;
+        Ok(Self {
+            keys: s.to_owned(),
+            value,
+        })
+    }
+}
+
+/// The sum of the complexities of all codes: the presses needed times the numeric part.
+pub fn complexity_sum(codes: &[Code], robots: usize) -> u64 {
+    let numeric = Keypad::numeric();
+    let mut counter = PressCounter::new();
+    codes
+        .iter()
+        .map(|code| counter.code_cost(&numeric, &code.keys, robots) * code.value)
+        .sum()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Code>;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        complexity_sum(input, 2).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        complexity_sum(input, 25).into()
+    }
+}
@@ -1,3 +1,15 @@
+use common::{
+    input::{InputProvider, Variant},
+    Solution,
+};
+use day_21::Day21;
+
 fn main() {
-    println!("Hello, world!");
+    let contents = InputProvider::default()
+        .resolve(21, &Variant::Real)
+        .expect("Should be able to read input");
+
+    let codes = Day21::parse(&contents).expect("Should be able to parse door codes");
+    println!("Complexity with 2 robots: {}", Day21::part1(&codes));
+    println!("Complexity with 25 robots: {}", Day21::part2(&codes));
 }
@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use common::{parse_lines, Answer, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub struct SecretNumber(i64);
+
+impl FromStr for SecretNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+            .map(Self)
+            .map_err(|_| ParseError::at_token(s, s, "a secret number"))
+    }
+}
+
+impl SecretNumber {
+    fn nth(&self, n: usize) -> Self {
+        let mut result = *self;
+        for _ in 0..n {
+            result = result.next();
+        }
+        result
+    }
+
+    fn next(&self) -> Self {
+        let next = self.mix(self.0 * 64).prune();
+        let next = next.mix(next.0 / 32).prune();
+        next.mix(next.0 * 2048).prune()
+    }
+
+    fn mix(&self, value: i64) -> Self {
+        Self(self.0 ^ value).prune()
+    }
+
+    fn prune(&self) -> Self {
+        Self(self.0 % 16777216)
+    }
+
+    /// The price the buyer offers: the ones digit of the secret number.
+    fn price(&self) -> i8 {
+        (self.0 % 10) as i8
+    }
+
+    /// The prices offered for the initial secret number and the `n` secret numbers after it.
+    fn prices(&self, n: usize) -> impl Iterator<Item = i8> {
+        std::iter::successors(Some(*self), |secret_number| Some(secret_number.next()))
+            .take(n + 1)
+            .map(|secret_number| secret_number.price())
+    }
+}
+
+/// Price changes range from -9 to 9, so four of them fit into a base 19 number.
+const CHANGE_RANGE: usize = 19;
+const SEQUENCES: usize = CHANGE_RANGE.pow(4);
+
+/// Shifts another change into an encoded sequence, dropping the oldest one.
+fn push_change(sequence: usize, change: i8) -> usize {
+    (sequence * CHANGE_RANGE + (change + 9) as usize) % SEQUENCES
+}
+
+fn decode_sequence(mut sequence: usize) -> [i8; 4] {
+    let mut changes = [0; 4];
+    for change in changes.iter_mut().rev() {
+        *change = (sequence % CHANGE_RANGE) as i8 - 9;
+        sequence /= CHANGE_RANGE;
+    }
+    changes
+}
+
+/// The sequence of four price changes that makes the monkey sell for the most bananas, and how
+/// many bananas that is. `None` if no sequence sells for any bananas, like without buyers.
+///
+/// The monkey sells at the first occurrence of the sequence for every buyer, so the bananas are
+/// totalled per encoded sequence, skipping sequences already seen for the current buyer.
+pub fn best_sequence(buyers: &[SecretNumber]) -> Option<([i8; 4], u64)> {
+    let mut bananas = vec![0u64; SEQUENCES];
+    // the last buyer each sequence was seen for, offset by one so that zero means never
+    let mut seen_by = vec![0usize; SEQUENCES];
+
+    for (buyer, secret_number) in buyers.iter().enumerate() {
+        let mut prices = secret_number.prices(2000);
+        let Some(mut previous) = prices.next() else {
+            continue;
+        };
+        let mut sequence = 0;
+        for (idx, price) in prices.enumerate() {
+            sequence = push_change(sequence, price - previous);
+            previous = price;
+            if idx >= 3 && seen_by[sequence] != buyer + 1 {
+                seen_by[sequence] = buyer + 1;
+                bananas[sequence] += price as u64;
+            }
+        }
+    }
+
+    let (sequence, &most) = bananas
+        .iter()
+        .enumerate()
+        .max_by_key(|&(_, total)| total)
+        .filter(|&(_, &most)| most > 0)?;
+    Some((decode_sequence(sequence), most))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<SecretNumber>;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|secret_number| secret_number.nth(2000).0)
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        best_sequence(input).map_or(Answer::Unsolved, |(_, bananas)| bananas.into())
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use common::{Answer, ParseError, Solution};
+
+/// The LAN: every computer gets an id in the order it first shows up, the connections are kept
+/// by id.
+#[derive(Debug, Default)]
+pub struct Network {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    neighbours: Vec<HashSet<usize>>,
+}
+
+impl FromStr for Network {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut network = Self::default();
+        for (idx, line) in s.lines().enumerate() {
+            let (a, b) = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::at_end(line, "`-`").on_line(idx + 1))?;
+            let (a, b) = (network.intern(a), network.intern(b));
+            network.neighbours[a].insert(b);
+            network.neighbours[b].insert(a);
+        }
+        Ok(network)
+    }
+}
+
+impl Network {
+    fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_owned());
+        self.ids.insert(name.to_owned(), id);
+        self.neighbours.push(HashSet::new());
+        id
+    }
+
+    /// The names of the computers, sorted.
+    fn sorted_names<const N: usize>(&self, ids: [usize; N]) -> [&str; N] {
+        let mut names = ids.map(|id| self.names[id].as_str());
+        names.sort();
+        names
+    }
+
+    /// Every set of three computers connected to each other with at least one computer matching
+    /// `filter`.
+    pub fn triangles(&self, filter: impl Fn(&str) -> bool) -> Vec<[&str; 3]> {
+        let mut triangles = vec![];
+        for (a, neighbours) in self.neighbours.iter().enumerate() {
+            // every triangle is found once, from its lowest id
+            for &b in neighbours.iter().filter(|&&b| b > a) {
+                for &c in self.neighbours[b].iter().filter(|&&c| c > b) {
+                    if neighbours.contains(&c) {
+                        let triangle = self.sorted_names([a, b, c]);
+                        if triangle.iter().any(|name| filter(name)) {
+                            triangles.push(triangle);
+                        }
+                    }
+                }
+            }
+        }
+        triangles
+    }
+
+    /// Every set of computers connected to each other that can't be extended by another one,
+    /// each sorted by name.
+    pub fn maximal_cliques(&self) -> Vec<Vec<&str>> {
+        let mut cliques = vec![];
+        let candidates = (0..self.names.len()).collect();
+        self.bron_kerbosch(&mut vec![], candidates, HashSet::new(), &mut cliques);
+        cliques
+            .into_iter()
+            .map(|clique| {
+                let mut names: Vec<_> = clique.iter().map(|&id| self.names[id].as_str()).collect();
+                names.sort();
+                names
+            })
+            .collect()
+    }
+
+    /// Bron–Kerbosch with pivoting: extends `clique` by the `candidates` connected to all of it,
+    /// skipping the computers in `excluded` whose cliques have been reported already.
+    ///
+    /// Every maximal clique contains the pivot or one of the computers not connected to it, so
+    /// only those have to be tried. The pivot is the computer connected to most candidates.
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: HashSet<usize>,
+        mut excluded: HashSet<usize>,
+        cliques: &mut Vec<Vec<usize>>,
+    ) {
+        let Some(&pivot) = candidates
+            .iter()
+            .chain(&excluded)
+            .max_by_key(|&&id| self.neighbours[id].intersection(&candidates).count())
+        else {
+            cliques.push(clique.clone());
+            return;
+        };
+
+        let tried: Vec<_> = candidates
+            .difference(&self.neighbours[pivot])
+            .copied()
+            .collect();
+        for id in tried {
+            let neighbours = &self.neighbours[id];
+            clique.push(id);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(neighbours).copied().collect(),
+                excluded.intersection(neighbours).copied().collect(),
+                cliques,
+            );
+            clique.pop();
+            candidates.remove(&id);
+            excluded.insert(id);
+        }
+    }
+
+    /// The largest set of computers connected to each other, sorted by name.
+    pub fn maximum_clique(&self) -> Vec<&str> {
+        self.maximal_cliques()
+            .into_iter()
+            .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+            .unwrap_or_default()
+    }
+
+    /// The password of the LAN party: the names of the largest clique, joined by commas.
+    pub fn password(&self) -> String {
+        self.maximum_clique().join(",")
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Network;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.triangles(|name| name.starts_with('t')).len().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.password().into()
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{Device, Op};
+
+/// The number of pairs of gates whose outputs were swapped.
+const SWAPPED_PAIRS: usize = 4;
+
+fn is_input(wire: &str) -> bool {
+    wire.starts_with('x') || wire.starts_with('y')
+}
+
+impl Device {
+    /// The number of bits of each of the two summands.
+    pub(crate) fn input_bits(&self) -> usize {
+        self.gates
+            .keys()
+            .filter(|wire| wire.starts_with('x'))
+            .count()
+    }
+
+    /// Every gate that isn't a fixed value, as `(output, op, a, b)`.
+    fn logic_gates(&self) -> impl Iterator<Item = (&str, Op, &str, &str)> {
+        self.gates.iter().filter_map(|(wire, gate)| {
+            let (op, a, b) = gate.logic()?;
+            Some((wire.as_str(), op, a, b))
+        })
+    }
+
+    /// The wires whose gate doesn't fit its role in a ripple-carry adder.
+    ///
+    /// Bit `i` of such an adder is built from five gates:
+    ///
+    /// - `x XOR y` is the half sum and only feeds the XOR and AND of the carry
+    /// - `half XOR carry` is the output `z`
+    /// - `x AND y` and `half AND carry` only feed the OR
+    /// - their OR is the carry into the next bit, or the highest output bit
+    ///
+    /// Bit 0 has no carry in, so `x00 XOR y00` is `z00` and `x00 AND y00` is the first carry.
+    pub fn suspect_wires(&self) -> BTreeSet<String> {
+        let highest = format!("z{:02}", self.input_bits());
+        let mut consumers: HashMap<&str, Vec<Op>> = HashMap::new();
+        for (_, op, a, b) in self.logic_gates() {
+            consumers.entry(a).or_default().push(op);
+            consumers.entry(b).or_default().push(op);
+        }
+        let feeds = |wire: &str, op: Op| consumers.get(wire).is_some_and(|ops| ops.contains(&op));
+
+        let mut suspects = BTreeSet::new();
+        for (out, op, a, b) in self.logic_gates() {
+            let first_bit = matches!((a, b), ("x00", "y00") | ("y00", "x00"));
+            let fits = match op {
+                _ if out == highest => op == Op::Or,
+                _ if out.starts_with('z') => op == Op::Xor && (!is_input(a) || out == "z00"),
+                Op::Xor if first_bit => false,
+                Op::Xor => is_input(a) && feeds(out, Op::Xor) && feeds(out, Op::And),
+                Op::And if first_bit => feeds(out, Op::Xor) && feeds(out, Op::And),
+                Op::And => feeds(out, Op::Or) && !feeds(out, Op::Xor),
+                Op::Or => feeds(out, Op::Xor) && feeds(out, Op::And),
+            };
+            if !fits {
+                suspects.insert(out.to_owned());
+            }
+        }
+        suspects
+    }
+
+    /// The pairs of wires whose gate outputs were swapped, each pair sorted.
+    ///
+    /// The suspects tell which wires were swapped but not which with which, so every way of
+    /// pairing them up is tried until the device adds correctly. Devices with more suspects than
+    /// the swapped pairs can explain aren't adders at all.
+    pub fn swapped_pairs(&self) -> Option<Vec<(String, String)>> {
+        let suspects: Vec<_> = self.suspect_wires().into_iter().collect();
+        if suspects.len() > 2 * SWAPPED_PAIRS {
+            return None;
+        }
+        self.pair_up(&suspects, &mut vec![])
+    }
+
+    fn pair_up(
+        &self,
+        remaining: &[String],
+        pairs: &mut Vec<(String, String)>,
+    ) -> Option<Vec<(String, String)>> {
+        let Some((first, rest)) = remaining.split_first() else {
+            return self.with_swaps(pairs).is_adder().then(|| pairs.clone());
+        };
+        for (idx, second) in rest.iter().enumerate() {
+            let mut others = rest.to_vec();
+            others.remove(idx);
+            pairs.push((first.clone(), second.clone()));
+            if let Some(found) = self.pair_up(&others, pairs) {
+                return Some(found);
+            }
+            pairs.pop();
+        }
+        None
+    }
+
+    /// A copy of the device with the outputs of every pair of wires swapped.
+    fn with_swaps(&self, pairs: &[(String, String)]) -> Device {
+        let mut device = self.clone();
+        for (a, b) in pairs {
+            let gate_a = device.gates.remove(a);
+            let gate_b = device.gates.remove(b);
+            if let Some(gate) = gate_a {
+                device.gates.insert(b.clone(), gate);
+            }
+            if let Some(gate) = gate_b {
+                device.gates.insert(a.clone(), gate);
+            }
+        }
+        device
+    }
+
+    /// The sorted, comma separated list of the wires that have to be swapped back.
+    pub fn swapped_wires(&self) -> Option<String> {
+        let mut wires: Vec<_> = self
+            .swapped_pairs()?
+            .into_iter()
+            .flat_map(|(a, b)| [a, b])
+            .collect();
+        wires.sort();
+        Some(wires.join(","))
+    }
+
+    /// Whether the device adds every bit of a set of test summands correctly: every single bit,
+    /// every carry chain and some scrambled values.
+    fn is_adder(&self) -> bool {
+        let Ok(circuit) = self.compile() else {
+            return false;
+        };
+        let bits = circuit.input_bits();
+        if bits == 0 || bits > 63 {
+            return false;
+        }
+        let mask = (1u64 << bits) - 1;
+        let mut summands = vec![(mask, 1), (mask, mask), (0, 0)];
+        for bit in 0..bits {
+            summands.extend([(1 << bit, 0), (0, 1 << bit), (1 << bit, 1 << bit)]);
+        }
+        let mut state = 0x2024_u64;
+        for _ in 0..16 {
+            // splitmix64
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^= z >> 31;
+            summands.push((z & mask, (z >> 32 ^ z << 7) & mask));
+        }
+        circuit
+            .add_all(&summands)
+            .into_iter()
+            .zip(&summands)
+            .all(|(sum, &(x, y))| sum == x + y)
+    }
+}
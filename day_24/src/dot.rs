@@ -0,0 +1,109 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use crate::{Device, Gate};
+
+/// What to show in a graph of a device besides its gates.
+#[derive(Debug, Clone, Default)]
+pub struct DotOptions {
+    highlighted: BTreeSet<String>,
+    values: bool,
+}
+
+impl DotOptions {
+    /// Fills the gates driving these wires in red, e.g. `Device::suspect_wires`.
+    pub fn with_highlighted(mut self, wires: BTreeSet<String>) -> Self {
+        self.highlighted = wires;
+        self
+    }
+
+    /// Labels every edge with the value of its wire for the inputs of the device.
+    pub fn with_values(mut self, values: bool) -> Self {
+        self.values = values;
+        self
+    }
+}
+
+impl Device {
+    /// The device as a Graphviz graph: one node per gate shaped by its operation, and the
+    /// inputs and outputs in a row each, ordered by bit.
+    pub fn to_dot(&self, options: &DotOptions) -> String {
+        let values = options
+            .values
+            .then(|| self.compile().ok().map(|circuit| circuit.wire_values()));
+        let mut dot = String::new();
+        let mut line = |line: String| {
+            dot.push_str(&line);
+            dot.push('\n');
+        };
+
+        line("digraph G {".to_owned());
+        line("    rankdir=TB;".to_owned());
+        line("    node [fontname=\"monospace\"];".to_owned());
+        line(String::new());
+
+        for prefix in ['x', 'y', 'z'] {
+            let mut wires: Vec<_> = self
+                .gates
+                .keys()
+                .filter(|wire| wire.starts_with(prefix))
+                .collect();
+            wires.sort();
+            let mut rank = String::new();
+            for wire in &wires {
+                write!(rank, " \"{wire}\";").expect("Writing to a string should not fail");
+            }
+            line(format!("    {{ rank=same;{rank} }}"));
+            // invisible edges keep the bits of a row in order
+            for pair in wires.windows(2) {
+                line(format!(
+                    "    \"{}\" -> \"{}\" [style=invis];",
+                    pair[0], pair[1]
+                ));
+            }
+        }
+        line(String::new());
+
+        let mut wires: Vec<_> = self.gates.keys().collect();
+        wires.sort();
+        for wire in &wires {
+            let (op, shape) = match &self.gates[*wire] {
+                Gate::Value(_) => ("", "plaintext"),
+                Gate::And(..) => ("AND", "box"),
+                Gate::Or(..) => ("OR", "ellipse"),
+                Gate::Xor(..) => ("XOR", "diamond"),
+            };
+            let label = if op.is_empty() {
+                wire.to_string()
+            } else {
+                format!("{wire}\\n{op}")
+            };
+            let fill = if options.highlighted.contains(*wire) {
+                ", style=filled, fillcolor=\"#ff8080\""
+            } else {
+                ""
+            };
+            line(format!(
+                "    \"{wire}\" [label=\"{label}\", shape={shape}{fill}];"
+            ));
+        }
+        line(String::new());
+
+        for wire in &wires {
+            let (a, b) = match &self.gates[*wire] {
+                Gate::Value(_) => continue,
+                Gate::And(a, b) | Gate::Or(a, b) | Gate::Xor(a, b) => (a, b),
+            };
+            for input in [a, b] {
+                let label = match &values {
+                    Some(Some(values)) => format!(" [label=\"{}\"]", values[input] as u8),
+                    Some(None) => " [label=\"?\"]".to_owned(),
+                    None => String::new(),
+                };
+                line(format!("    \"{input}\" -> \"{wire}\"{label};"));
+            }
+        }
+
+        line("}".to_owned());
+        dot
+    }
+}
@@ -0,0 +1,143 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{Answer, ParseError, Solution};
+
+mod adder;
+mod dot;
+mod netlist;
+mod simulator;
+mod symbolic;
+mod validate;
+
+pub use dot::DotOptions;
+pub use simulator::Circuit;
+pub use symbolic::{AdderCheck, Anf, Expr, Var};
+use validate::Drivers;
+pub use validate::{CircuitError, LoadError};
+
+#[derive(Clone)]
+enum Gate {
+    And(String, String),
+    Or(String, String),
+    Xor(String, String),
+    Value(bool),
+}
+
+/// The operation of a gate, without its inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Gate {
+    /// The operation and inputs of a logic gate, `None` for a fixed value.
+    fn logic(&self) -> Option<(Op, &str, &str)> {
+        match self {
+            Gate::And(a, b) => Some((Op::And, a, b)),
+            Gate::Or(a, b) => Some((Op::Or, a, b)),
+            Gate::Xor(a, b) => Some((Op::Xor, a, b)),
+            Gate::Value(_) => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Device {
+    gates: HashMap<String, Gate>,
+}
+
+impl FromStr for Device {
+    type Err = LoadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate();
+        let inputs = lines.by_ref().take_while(|(_, line)| !line.is_empty());
+
+        let mut gates = HashMap::new();
+        let mut drivers = Drivers::default();
+
+        for (idx, line) in inputs {
+            let (input, value) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::at_end(line, "`: `").on_line(idx + 1))?;
+            let value = match value {
+                "0" => false,
+                "1" => true,
+                _ => {
+                    let err = ParseError::at_token(line, value, "`0` or `1`");
+                    return Err(err.on_line(idx + 1).into());
+                }
+            };
+            drivers.drive(input, idx + 1);
+            gates.insert(input.to_owned(), Gate::Value(value));
+        }
+
+        for (idx, line) in lines {
+            let (a, b, res, value) = parse_gate(line).map_err(|err| err.on_line(idx + 1))?;
+            let value = match value {
+                "AND" => Gate::And(a.to_owned(), b.to_owned()),
+                "OR" => Gate::Or(a.to_owned(), b.to_owned()),
+                _ => Gate::Xor(a.to_owned(), b.to_owned()),
+            };
+            drivers.drive(res, idx + 1);
+            gates.insert(res.to_owned(), value);
+        }
+
+        let device = Self { gates };
+        device.validate(drivers.into_duplicates())?;
+        Ok(device)
+    }
+}
+
+/// Splits `a OP b -> res` into `(a, b, res, OP)`.
+fn parse_gate(line: &str) -> Result<(&str, &str, &str, &str), ParseError> {
+    let mut tokens = line.split_ascii_whitespace();
+    let mut next = |expected: &str| {
+        tokens
+            .next()
+            .ok_or_else(|| ParseError::at_end(line, expected))
+    };
+    let a = next("a wire")?;
+    let op = next("`AND`, `OR` or `XOR`")?;
+    if !matches!(op, "AND" | "OR" | "XOR") {
+        return Err(ParseError::at_token(line, op, "`AND`, `OR` or `XOR`"));
+    }
+    let b = next("a wire")?;
+    let arrow = next("`->`")?;
+    if arrow != "->" {
+        return Err(ParseError::at_token(line, arrow, "`->`"));
+    }
+    let res = next("a wire")?;
+    if let Some(extra) = tokens.next() {
+        return Err(ParseError::at_token(line, extra, "the end of the line"));
+    }
+    Ok((a, b, res, op))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Device;
+    type Err = LoadError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .compile()
+            .map_or(Answer::Unsolved, |circuit| circuit.output().into())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        match input.swapped_wires() {
+            // a device that already adds correctly, an empty answer couldn't be recorded
+            Some(wires) if wires.is_empty() => "none".into(),
+            Some(wires) => wires.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
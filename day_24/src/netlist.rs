@@ -0,0 +1,318 @@
+use std::{collections::HashMap, fmt::Write};
+
+use common::ParseError;
+
+use crate::{validate::Drivers, Device, Gate, LoadError};
+
+/// Verilog keywords short enough to be mistaken for a wire name.
+const VERILOG_KEYWORDS: &[&str] = &[
+    "and", "assign", "begin", "buf", "case", "else", "end", "for", "if", "inout", "input",
+    "module", "nand", "nor", "not", "or", "output", "reg", "tri", "wire", "wor", "xnor", "xor",
+];
+
+/// Splits a summand or output bit like `x07` into its bus and bit.
+pub(crate) fn bus_bit(wire: &str) -> Option<(char, usize)> {
+    let bus = wire
+        .chars()
+        .next()
+        .filter(|c| matches!(c, 'x' | 'y' | 'z'))?;
+    let bit = &wire[1..];
+    if bit.is_empty() || !bit.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((bus, bit.parse().ok()?))
+}
+
+/// The name of a wire in Verilog: a bit of a bus, or the wire itself, escaped if necessary.
+fn verilog_name(wire: &str) -> String {
+    if let Some((bus, bit)) = bus_bit(wire) {
+        return format!("{bus}[{bit}]");
+    }
+    let plain = wire.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && wire.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    match plain && !VERILOG_KEYWORDS.contains(&wire) {
+        true => wire.to_owned(),
+        // escaped identifiers end at the next whitespace
+        false => format!("\\{wire} "),
+    }
+}
+
+impl Device {
+    fn sorted_gates(&self) -> Vec<(&str, &Gate)> {
+        let mut gates: Vec<_> = self.gates.iter().map(|(w, g)| (w.as_str(), g)).collect();
+        gates.sort_by_key(|&(wire, _)| wire);
+        gates
+    }
+
+    /// The width of each of the `x`, `y` and `z` buses.
+    fn bus_widths(&self) -> HashMap<char, usize> {
+        let mut widths = HashMap::new();
+        for (bus, bit) in self.gates.keys().filter_map(|wire| bus_bit(wire)) {
+            let width = widths.entry(bus).or_default();
+            *width = (bit + 1).max(*width);
+        }
+        widths
+    }
+
+    /// The device as a structural Verilog module with `x` and `y` input buses, a `z` output bus
+    /// and one primitive gate per gate. The values of the inputs aren't part of the module.
+    pub fn to_verilog(&self, module: &str) -> String {
+        let widths = self.bus_widths();
+        let gates = self.sorted_gates();
+        let scalar_inputs: Vec<_> = gates
+            .iter()
+            .filter(|(wire, gate)| matches!(gate, Gate::Value(_)) && bus_bit(wire).is_none())
+            .map(|(wire, _)| verilog_name(wire))
+            .collect();
+
+        let mut ports: Vec<String> = ['x', 'y', 'z']
+            .into_iter()
+            .filter(|bus| widths.contains_key(bus))
+            .map(|bus| bus.to_string())
+            .collect();
+        ports.extend(scalar_inputs.iter().cloned());
+
+        let mut verilog = String::new();
+        let mut line = |line: String| {
+            verilog.push_str(&line);
+            verilog.push('\n');
+        };
+        line(format!(
+            "module {}({});",
+            verilog_name(module),
+            ports.join(", ")
+        ));
+        for (bus, direction) in [('x', "input"), ('y', "input"), ('z', "output")] {
+            if let Some(width) = widths.get(&bus) {
+                line(format!("  {direction} [{}:0] {bus};", width - 1));
+            }
+        }
+        for input in &scalar_inputs {
+            line(format!("  input {input};"));
+        }
+        line(String::new());
+
+        for (wire, gate) in &gates {
+            if !matches!(gate, Gate::Value(_)) && bus_bit(wire).is_none() {
+                line(format!("  wire {};", verilog_name(wire)));
+            }
+        }
+        line(String::new());
+
+        for (idx, (wire, gate)) in gates
+            .iter()
+            .filter(|(_, gate)| !matches!(gate, Gate::Value(_)))
+            .enumerate()
+        {
+            let (primitive, a, b) = match gate {
+                Gate::And(a, b) => ("and", a, b),
+                Gate::Or(a, b) => ("or", a, b),
+                Gate::Xor(a, b) => ("xor", a, b),
+                Gate::Value(_) => unreachable!("inputs are filtered out"),
+            };
+            line(format!(
+                "  {primitive} g{idx} ({}, {}, {});",
+                verilog_name(wire),
+                verilog_name(a),
+                verilog_name(b)
+            ));
+        }
+        line("endmodule".to_owned());
+        verilog
+    }
+
+    /// The device as a BLIF model: every input is a primary input, every `z` wire a primary
+    /// output and every gate a two input `.names` cover.
+    ///
+    /// BLIF has no notion of input values, so they are kept in comments like `# x00: 1`, which
+    /// [`Device::from_blif`] reads back.
+    pub fn to_blif(&self, model: &str) -> String {
+        let gates = self.sorted_gates();
+        let mut blif = format!(".model {model}\n");
+        let mut inputs = vec![];
+        for (wire, gate) in &gates {
+            if let Gate::Value(value) = gate {
+                writeln!(blif, "# {wire}: {}", *value as u8)
+                    .expect("Writing to a string should not fail");
+                inputs.push(*wire);
+            }
+        }
+        let outputs: Vec<_> = gates
+            .iter()
+            .map(|&(wire, _)| wire)
+            .filter(|wire| matches!(bus_bit(wire), Some(('z', _))))
+            .collect();
+        writeln!(blif, ".inputs {}", inputs.join(" "))
+            .expect("Writing to a string should not fail");
+        writeln!(blif, ".outputs {}", outputs.join(" "))
+            .expect("Writing to a string should not fail");
+
+        for (wire, gate) in &gates {
+            let (cover, a, b) = match gate {
+                Gate::And(a, b) => ("11 1\n", a, b),
+                Gate::Or(a, b) => ("1- 1\n-1 1\n", a, b),
+                Gate::Xor(a, b) => ("01 1\n10 1\n", a, b),
+                Gate::Value(_) => continue,
+            };
+            writeln!(blif, ".names {a} {b} {wire}").expect("Writing to a string should not fail");
+            blif.push_str(cover);
+        }
+        blif.push_str(".end\n");
+        blif
+    }
+
+    /// Reads a device back from the subset of BLIF written by [`Device::to_blif`]: a single
+    /// model of primary inputs and two input covers that are an AND, an OR or an XOR. Inputs
+    /// without a value comment are 0.
+    pub fn from_blif(s: &str) -> Result<Device, LoadError> {
+        let mut gates = HashMap::new();
+        let mut drivers = Drivers::default();
+        let mut values = HashMap::new();
+        let mut cover: Option<Cover> = None;
+
+        for (number, line) in logical_lines(s) {
+            let line = line.as_str();
+            if let Some(comment) = line.strip_prefix('#') {
+                if let Some((wire, value)) = comment.trim().split_once(": ") {
+                    values.insert(wire.to_owned(), value == "1");
+                }
+                continue;
+            }
+            // anything else after a `#` is a comment too
+            let line = line.split('#').next().unwrap_or_default();
+            let mut tokens = line.split_ascii_whitespace();
+            let Some(first) = tokens.next() else {
+                continue;
+            };
+
+            if !first.starts_with('.') {
+                let Some(cover) = cover.as_mut() else {
+                    let expected = "`.model`, `.inputs`, `.outputs`, `.names` or `.end`";
+                    return Err(ParseError::at_token(line, first, expected)
+                        .on_line(number)
+                        .into());
+                };
+                cover
+                    .add_row(line, first, tokens.next())
+                    .map_err(|err| err.on_line(number))?;
+                continue;
+            }
+
+            if let Some(finished) = cover.take() {
+                finished.finish(&mut gates)?;
+            }
+            match first {
+                ".model" | ".outputs" => {}
+                ".inputs" => {
+                    for input in tokens {
+                        drivers.drive(input, number);
+                        gates.insert(input.to_owned(), Gate::Value(false));
+                    }
+                }
+                ".names" => {
+                    let wires: Vec<_> = tokens.collect();
+                    let [a, b, out] = wires[..] else {
+                        let expected = "two inputs and an output";
+                        return Err(ParseError::at_end(line, expected).on_line(number).into());
+                    };
+                    drivers.drive(out, number);
+                    cover = Some(Cover {
+                        inputs: (a.to_owned(), b.to_owned()),
+                        output: out.to_owned(),
+                        header: line.to_owned(),
+                        number,
+                        on_set: [false; 4],
+                    });
+                }
+                ".end" => break,
+                _ => {
+                    let expected = "`.model`, `.inputs`, `.outputs`, `.names` or `.end`";
+                    return Err(ParseError::at_token(line, first, expected)
+                        .on_line(number)
+                        .into());
+                }
+            }
+        }
+        if let Some(finished) = cover.take() {
+            finished.finish(&mut gates)?;
+        }
+        for (wire, value) in values {
+            if let Some(Gate::Value(input)) = gates.get_mut(&wire) {
+                *input = value;
+            }
+        }
+
+        let device = Device { gates };
+        device.validate(drivers.into_duplicates())?;
+        Ok(device)
+    }
+}
+
+/// The lines of a BLIF file with continuations joined, numbered by the line they start on.
+fn logical_lines(s: &str) -> Vec<(usize, String)> {
+    let mut lines = vec![];
+    let mut pending: Option<(usize, String)> = None;
+    for (idx, line) in s.lines().enumerate() {
+        let (number, mut joined) = pending.take().unwrap_or((idx + 1, String::new()));
+        match line.strip_suffix('\\') {
+            Some(continued) => {
+                joined.push_str(continued);
+                joined.push(' ');
+                pending = Some((number, joined));
+            }
+            None => {
+                joined.push_str(line);
+                lines.push((number, joined));
+            }
+        }
+    }
+    lines.extend(pending);
+    lines
+}
+
+/// A `.names` cover being read: the input combinations for which the output is 1.
+struct Cover {
+    inputs: (String, String),
+    output: String,
+    header: String,
+    number: usize,
+    /// Indexed by `a * 2 + b`.
+    on_set: [bool; 4],
+}
+
+impl Cover {
+    fn add_row(&mut self, line: &str, plane: &str, output: Option<&str>) -> Result<(), ParseError> {
+        if plane.len() != 2 || !plane.bytes().all(|b| matches!(b, b'0' | b'1' | b'-')) {
+            return Err(ParseError::at_token(line, plane, "two of `0`, `1` or `-`"));
+        }
+        match output {
+            Some("1") => {}
+            Some(token) => return Err(ParseError::at_token(line, token, "`1`")),
+            None => return Err(ParseError::at_end(line, "`1`")),
+        }
+        let matches =
+            |literal: u8, value: usize| literal == b'-' || (literal - b'0') as usize == value;
+        let plane = plane.as_bytes();
+        for (combination, on) in self.on_set.iter_mut().enumerate() {
+            if matches(plane[0], combination >> 1) && matches(plane[1], combination & 1) {
+                *on = true;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self, gates: &mut HashMap<String, Gate>) -> Result<(), LoadError> {
+        let (a, b) = self.inputs;
+        let gate = match self.on_set {
+            [false, false, false, true] => Gate::And(a, b),
+            [false, true, true, true] => Gate::Or(a, b),
+            [false, true, true, false] => Gate::Xor(a, b),
+            _ => {
+                let err = ParseError::new(1, 1, &self.header, "a cover of an AND, OR or XOR");
+                return Err(err.on_line(self.number).into());
+            }
+        };
+        gates.insert(self.output, gate);
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use crate::{CircuitError, Device, Gate, Op};
+
+/// A gate of a compiled circuit: `out = a op b`, all wires by id.
+#[derive(Debug, Clone, Copy)]
+struct Step {
+    op: Op,
+    a: usize,
+    b: usize,
+    out: usize,
+}
+
+/// A device compiled for evaluation: wires are numbered and the gates sorted so that every gate
+/// comes after the gates driving its inputs.
+///
+/// Every wire carries 64 lanes, so up to 64 pairs of summands are added in a single pass.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    wires: Vec<String>,
+    steps: Vec<Step>,
+    x: Vec<usize>,
+    y: Vec<usize>,
+    z: Vec<usize>,
+    /// Wires with a fixed value that aren't summand bits.
+    constants: Vec<(usize, bool)>,
+    /// The summands set in the device.
+    inputs: (u64, u64),
+}
+
+/// The ids of the wires starting with `prefix`, ordered by bit.
+fn bus(ids: &HashMap<&str, usize>, prefix: char) -> Vec<usize> {
+    let mut wires: Vec<_> = ids
+        .iter()
+        .filter(|(wire, _)| wire.starts_with(prefix))
+        .collect();
+    wires.sort();
+    wires.into_iter().map(|(_, &id)| id).collect()
+}
+
+impl Device {
+    /// Compiles the device, failing if a wire is missing or the gates form a cycle.
+    pub fn compile(&self) -> Result<Circuit, CircuitError> {
+        self.check_evaluable()?;
+
+        let mut names: Vec<&str> = self.gates.keys().map(String::as_str).collect();
+        names.sort();
+        let ids: HashMap<&str, usize> = names.iter().enumerate().map(|(id, &w)| (w, id)).collect();
+
+        // depth first post-order, so inputs are always evaluated before the gates using them
+        let mut state = vec![Visit::New; names.len()];
+        let mut steps = vec![];
+        for id in 0..names.len() {
+            self.visit(id, &names, &ids, &mut state, &mut steps);
+        }
+
+        let (x, y) = (bus(&ids, 'x'), bus(&ids, 'y'));
+        let mut constants = vec![];
+        let mut inputs = (0, 0);
+        for (id, name) in names.iter().enumerate() {
+            if let Gate::Value(value) = self.gates[*name] {
+                if let Some(bit) = x.iter().position(|&x| x == id) {
+                    inputs.0 |= (value as u64) << bit;
+                } else if let Some(bit) = y.iter().position(|&y| y == id) {
+                    inputs.1 |= (value as u64) << bit;
+                } else {
+                    constants.push((id, value));
+                }
+            }
+        }
+
+        Ok(Circuit {
+            wires: names.into_iter().map(str::to_owned).collect(),
+            steps,
+            z: bus(&ids, 'z'),
+            x,
+            y,
+            constants,
+            inputs,
+        })
+    }
+
+    fn visit(
+        &self,
+        id: usize,
+        names: &[&str],
+        ids: &HashMap<&str, usize>,
+        state: &mut [Visit],
+        steps: &mut Vec<Step>,
+    ) {
+        match state[id] {
+            Visit::Done => return,
+            Visit::Active => unreachable!("devices with cycles are rejected before compiling"),
+            Visit::New => state[id] = Visit::Active,
+        }
+        let Some((op, a, b)) = self.gates[names[id]].logic() else {
+            state[id] = Visit::Done;
+            return;
+        };
+        let (a, b) = (ids[a], ids[b]);
+        self.visit(a, names, ids, state, steps);
+        self.visit(b, names, ids, state, steps);
+        steps.push(Step { op, a, b, out: id });
+        state[id] = Visit::Done;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    Active,
+    Done,
+}
+
+/// Spreads a value over all lanes. Bits beyond the 64 of the value are zero.
+fn broadcast(value: u64, bits: usize) -> Vec<u64> {
+    (0..bits)
+        .map(|bit| match value.checked_shr(bit as u32).unwrap_or(0) & 1 {
+            1 => !0,
+            _ => 0,
+        })
+        .collect()
+}
+
+impl Circuit {
+    /// The number of bits of the summands.
+    pub fn input_bits(&self) -> usize {
+        self.x.len().max(self.y.len())
+    }
+
+    /// Every wire for the summands given in bit-sliced form: bit `i` of every summand in lane
+    /// `j` is bit `j` of `x[i]` and `y[i]`.
+    fn run(&self, x: &[u64], y: &[u64]) -> Vec<u64> {
+        let mut lanes = vec![0; self.wires.len()];
+        for (&id, &value) in self.x.iter().zip(x) {
+            lanes[id] = value;
+        }
+        for (&id, &value) in self.y.iter().zip(y) {
+            lanes[id] = value;
+        }
+        for &(id, value) in &self.constants {
+            lanes[id] = if value { !0 } else { 0 };
+        }
+        for step in &self.steps {
+            let (a, b) = (lanes[step.a], lanes[step.b]);
+            lanes[step.out] = match step.op {
+                Op::And => a & b,
+                Op::Or => a | b,
+                Op::Xor => a ^ b,
+            };
+        }
+        lanes
+    }
+
+    /// The outputs in bit-sliced form for summands in bit-sliced form, see [`Circuit::run`].
+    pub fn evaluate_sliced(&self, x: &[u64], y: &[u64]) -> Vec<u64> {
+        let lanes = self.run(x, y);
+        self.z.iter().map(|&id| lanes[id]).collect()
+    }
+
+    /// The output for a single pair of summands.
+    pub fn add(&self, x: u64, y: u64) -> u64 {
+        let bits = self.input_bits();
+        let sliced = self.evaluate_sliced(&broadcast(x, bits), &broadcast(y, bits));
+        sliced
+            .iter()
+            .take(64)
+            .enumerate()
+            .fold(0, |sum, (bit, lane)| sum | (lane & 1) << bit)
+    }
+
+    /// The outputs for many pairs of summands, 64 at a time.
+    pub fn add_all(&self, summands: &[(u64, u64)]) -> Vec<u64> {
+        let bits = self.input_bits();
+        let mut sums = Vec::with_capacity(summands.len());
+        for chunk in summands.chunks(64) {
+            let (mut x, mut y) = (vec![0; bits], vec![0; bits]);
+            for (lane, &(a, b)) in chunk.iter().enumerate() {
+                for bit in 0..bits.min(64) {
+                    x[bit] |= (a >> bit & 1) << lane;
+                    y[bit] |= (b >> bit & 1) << lane;
+                }
+            }
+            let z = self.evaluate_sliced(&x, &y);
+            sums.extend((0..chunk.len()).map(|lane| {
+                z.iter()
+                    .take(64)
+                    .enumerate()
+                    .fold(0, |sum, (bit, slice)| sum | (slice >> lane & 1) << bit)
+            }));
+        }
+        sums
+    }
+
+    /// The output for the summands set in the device.
+    pub fn output(&self) -> u64 {
+        self.add(self.inputs.0, self.inputs.1)
+    }
+
+    /// The value of every wire for the summands set in the device.
+    pub fn wire_values(&self) -> HashMap<String, bool> {
+        let bits = self.input_bits();
+        let (x, y) = self.inputs;
+        let lanes = self.run(&broadcast(x, bits), &broadcast(y, bits));
+        self.wires
+            .iter()
+            .zip(lanes)
+            .map(|(wire, lane)| (wire.clone(), lane & 1 == 1))
+            .collect()
+    }
+}
@@ -0,0 +1,375 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
+
+use crate::{netlist::bus_bit, CircuitError, Device, Gate};
+
+/// A variable of an expression: a summand bit `x` or `y`, or `c`, the carry into a bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Var {
+    bit: usize,
+    bus: char,
+}
+
+impl Var {
+    fn new(bus: char, bit: usize) -> Self {
+        Self { bit, bus }
+    }
+}
+
+impl Display for Var {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{:02}", self.bus, self.bit)
+    }
+}
+
+/// An output of a device as a Boolean expression over the summand bits. Wires read by several
+/// gates are shared, not copied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Const(bool),
+    Var(Var),
+    And(Rc<Expr>, Rc<Expr>),
+    Or(Rc<Expr>, Rc<Expr>),
+    Xor(Rc<Expr>, Rc<Expr>),
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Const(value) => write!(f, "{}", *value as u8),
+            Self::Var(var) => write!(f, "{var}"),
+            Self::And(a, b) => write!(f, "({a} & {b})"),
+            Self::Or(a, b) => write!(f, "({a} | {b})"),
+            Self::Xor(a, b) => write!(f, "({a} ^ {b})"),
+        }
+    }
+}
+
+/// The most monomials shown of each side of a difference, see [`AdderCheck`].
+const SHOWN_TERMS: usize = 8;
+
+/// A product of variables, sorted and without repeats. The empty monomial is the constant 1.
+type Monomial = Vec<Var>;
+
+/// A Boolean function in algebraic normal form: the XOR of AND-monomials.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Anf {
+    terms: HashSet<Monomial>,
+}
+
+impl Anf {
+    fn constant(value: bool) -> Self {
+        Self {
+            terms: value.then(Vec::new).into_iter().collect(),
+        }
+    }
+
+    fn var(var: Var) -> Self {
+        Self {
+            terms: HashSet::from([vec![var]]),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    fn xor(&self, other: &Self) -> Self {
+        Self {
+            terms: self
+                .terms
+                .symmetric_difference(&other.terms)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// The product of both functions, or `None` if working it out would take more than
+    /// `max_terms` products of monomials.
+    fn and(&self, other: &Self, max_terms: usize) -> Option<Self> {
+        if self.len().saturating_mul(other.len()) > max_terms {
+            return None;
+        }
+        let mut terms = HashSet::new();
+        for a in &self.terms {
+            for b in &other.terms {
+                let mut product: Monomial = a.iter().chain(b).copied().collect();
+                product.sort_unstable();
+                product.dedup();
+                // equal monomials cancel out
+                if !terms.remove(&product) {
+                    terms.insert(product);
+                }
+            }
+        }
+        Some(Self { terms })
+    }
+
+    /// `a | b` is `a ^ b ^ ab`.
+    fn or(&self, other: &Self, max_terms: usize) -> Option<Self> {
+        Some(self.xor(other).xor(&self.and(other, max_terms)?))
+    }
+
+    /// The sum bit `bit` of an adder: `x ^ y ^ carry`.
+    fn sum_bit(bit: usize, carry: &Self) -> Self {
+        let (x, y) = (Anf::var(Var::new('x', bit)), Anf::var(Var::new('y', bit)));
+        x.xor(&y).xor(carry)
+    }
+
+    /// The carry out of bit `bit` of an adder: `xy ^ (x ^ y) carry`.
+    fn carry_out(bit: usize, carry: &Self) -> Self {
+        let (x, y) = (Anf::var(Var::new('x', bit)), Anf::var(Var::new('y', bit)));
+        let generate = x.and(&y, usize::MAX).expect("no limit");
+        let propagate = x.xor(&y).and(carry, usize::MAX).expect("no limit");
+        generate.xor(&propagate)
+    }
+
+    /// The monomials in a stable order: fewer variables first.
+    fn sorted(&self) -> Vec<&Monomial> {
+        let mut terms: Vec<_> = self.terms.iter().collect();
+        terms.sort_by(|a, b| (a.len(), a).cmp(&(b.len(), b)));
+        terms
+    }
+}
+
+/// Writes the monomials joined by `^`, `0` if there are none.
+fn write_terms<'a>(
+    f: &mut Formatter<'_>,
+    terms: impl IntoIterator<Item = &'a Monomial>,
+) -> std::fmt::Result {
+    let mut empty = true;
+    for term in terms {
+        if !empty {
+            write!(f, " ^ ")?;
+        }
+        empty = false;
+        if term.is_empty() {
+            write!(f, "1")?;
+        }
+        for var in term {
+            write!(f, "{var}")?;
+        }
+    }
+    if empty {
+        write!(f, "0")?;
+    }
+    Ok(())
+}
+
+impl Display for Anf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_terms(f, self.sorted())
+    }
+}
+
+/// How the outputs of a device compare to the outputs of an adder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdderCheck {
+    /// Every output bit is the sum bit of an adder.
+    Matches,
+    /// The lowest output bit that isn't the sum bit of an adder, in terms of the summand bits
+    /// and the carry into `bit`, see [`Device::check_adder`].
+    Diverges {
+        bit: usize,
+        actual: Anf,
+        expected: Anf,
+    },
+    /// Every output bit below `bit` matches, but `bit` got too large to compare.
+    TooLarge { bit: usize },
+}
+
+/// Writes at most [`SHOWN_TERMS`] of the monomials and how many are left out.
+fn write_shown(f: &mut Formatter<'_>, terms: &[&Monomial]) -> std::fmt::Result {
+    write_terms(f, terms.iter().copied().take(SHOWN_TERMS))?;
+    if terms.len() > SHOWN_TERMS {
+        write!(f, " ^ ... ({} more)", terms.len() - SHOWN_TERMS)?;
+    }
+    Ok(())
+}
+
+impl Display for AdderCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Matches => write!(f, "every output bit matches an adder"),
+            Self::Diverges {
+                bit,
+                actual,
+                expected,
+            } => {
+                // only the difference, the whole normal forms can be huge
+                let missing: Vec<_> = expected
+                    .sorted()
+                    .into_iter()
+                    .filter(|term| !actual.terms.contains(*term))
+                    .collect();
+                let extra: Vec<_> = actual
+                    .sorted()
+                    .into_iter()
+                    .filter(|term| !expected.terms.contains(*term))
+                    .collect();
+                write!(f, "z{bit:02} diverges from an adder:\n  missing: ")?;
+                write_shown(f, &missing)?;
+                write!(f, "\n  extra:   ")?;
+                write_shown(f, &extra)
+            }
+            Self::TooLarge { bit } => write!(
+                f,
+                "the output bits below z{bit:02} match an adder, z{bit:02} is too large to compare"
+            ),
+        }
+    }
+}
+
+impl Device {
+    /// The expression computed on `wire`, in terms of the summand bits.
+    pub fn expression(&self, wire: &str) -> Result<Rc<Expr>, CircuitError> {
+        self.check_evaluable()?;
+        self.build_expression(wire, &HashMap::new(), &mut HashMap::new())
+            .ok_or_else(|| CircuitError::UndefinedWire {
+                wire: wire.to_owned(),
+                reader: "the expression".to_owned(),
+            })
+    }
+
+    /// The expression computed on `wire`, with the wires in `cuts` standing for variables
+    /// instead of their gates.
+    fn build_expression(
+        &self,
+        wire: &str,
+        cuts: &HashMap<&str, Var>,
+        built: &mut HashMap<String, Rc<Expr>>,
+    ) -> Option<Rc<Expr>> {
+        if let Some(expr) = built.get(wire) {
+            return Some(expr.clone());
+        }
+        let mut operands = |a: &str, b: &str| {
+            Some((
+                self.build_expression(a, cuts, built)?,
+                self.build_expression(b, cuts, built)?,
+            ))
+        };
+        let expr = match (cuts.get(wire), self.gates.get(wire)?) {
+            (Some(&var), _) => Expr::Var(var),
+            (None, Gate::Value(value)) => match bus_bit(wire) {
+                Some((bus @ ('x' | 'y'), bit)) => Expr::Var(Var::new(bus, bit)),
+                _ => Expr::Const(*value),
+            },
+            (None, Gate::And(a, b)) => operands(a, b).map(|(a, b)| Expr::And(a, b))?,
+            (None, Gate::Or(a, b)) => operands(a, b).map(|(a, b)| Expr::Or(a, b))?,
+            (None, Gate::Xor(a, b)) => operands(a, b).map(|(a, b)| Expr::Xor(a, b))?,
+        };
+        let expr = Rc::new(expr);
+        built.insert(wire.to_owned(), expr.clone());
+        Some(expr)
+    }
+
+    /// Compares every output bit to the sum bit of an adder, lowest bit first.
+    ///
+    /// Expanded into the summand bits, sum bit `i` has `2^i` monomials. So once a bit matches,
+    /// the wire computing its carry out `xy ^ (x ^ y) c` stands for the variable `c` of the next
+    /// bit, and every output bit is compared to `x ^ y ^ c`. A carry that no wire computes on
+    /// its own is expanded instead, and the comparison stops at the first bit whose normal form
+    /// takes more than `max_terms`.
+    pub fn check_adder(&self, max_terms: usize) -> Result<AdderCheck, CircuitError> {
+        self.check_evaluable()?;
+        let bits = self.input_bits();
+        let mut logic: Vec<&str> = self
+            .gates
+            .iter()
+            .filter(|(_, gate)| gate.logic().is_some())
+            .map(|(wire, _)| wire.as_str())
+            .collect();
+        logic.sort();
+
+        let mut carries = HashMap::new();
+        // the carry into `bit`
+        let mut carry = Anf::default();
+        for bit in 0..=bits {
+            // the carries found so far change the expressions, so they are built for every bit,
+            // and the normal forms are remembered by expression, so every expression is kept alive
+            let mut built = HashMap::new();
+            let mut normal_forms = HashMap::new();
+            let wire = format!("z{bit:02}");
+            let expr = self
+                .build_expression(&wire, &carries, &mut built)
+                .ok_or_else(|| CircuitError::UndefinedWire {
+                    wire,
+                    reader: "the adder check".to_owned(),
+                })?;
+            let Some(actual) = normal_form(&expr, max_terms, &mut normal_forms) else {
+                return Ok(AdderCheck::TooLarge { bit });
+            };
+            // the bit above the summands is the carry out of the highest bit
+            let expected = if bit < bits {
+                Anf::sum_bit(bit, &carry)
+            } else {
+                carry.clone()
+            };
+            if actual != expected {
+                return Ok(AdderCheck::Diverges {
+                    bit,
+                    actual,
+                    expected,
+                });
+            }
+            if bit == bits {
+                break;
+            }
+
+            let carry_out = Anf::carry_out(bit, &carry);
+            // a wire computing the carry doesn't take many more monomials on the way
+            let limit = carry_out.len().saturating_mul(4).min(max_terms);
+            let mut small_forms = HashMap::new();
+            let found = logic.iter().find(|wire| {
+                self.build_expression(wire, &carries, &mut built)
+                    .and_then(|expr| normal_form(&expr, limit, &mut small_forms))
+                    .is_some_and(|anf| anf == carry_out)
+            });
+            carry = match found {
+                Some(wire) => {
+                    let var = Var::new('c', bit + 1);
+                    carries.insert(*wire, var);
+                    Anf::var(var)
+                }
+                None => carry_out,
+            };
+        }
+        Ok(AdderCheck::Matches)
+    }
+}
+
+/// The normal form of an expression, remembering the normal form of every shared
+/// subexpression. `None` once the normal form gets larger than `max_terms`.
+fn normal_form(
+    expr: &Rc<Expr>,
+    max_terms: usize,
+    memo: &mut HashMap<*const Expr, Option<Anf>>,
+) -> Option<Anf> {
+    if let Some(anf) = memo.get(&Rc::as_ptr(expr)) {
+        return anf.clone();
+    }
+    let mut operands = |a, b| {
+        Some((
+            normal_form(a, max_terms, memo)?,
+            normal_form(b, max_terms, memo)?,
+        ))
+    };
+    let anf = match expr.as_ref() {
+        Expr::Const(value) => Some(Anf::constant(*value)),
+        Expr::Var(var) => Some(Anf::var(*var)),
+        Expr::And(a, b) => operands(a, b).and_then(|(a, b)| a.and(&b, max_terms)),
+        Expr::Or(a, b) => operands(a, b).and_then(|(a, b)| a.or(&b, max_terms)),
+        Expr::Xor(a, b) => operands(a, b).map(|(a, b)| a.xor(&b)),
+    }
+    .filter(|anf| anf.len() <= max_terms);
+    // failures are remembered too, so shared subexpressions that are too large are given up once
+    memo.insert(Rc::as_ptr(expr), anf.clone());
+    anf
+}
@@ -0,0 +1,217 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use common::ParseError;
+
+use crate::{Device, Gate};
+
+/// A netlist that parses but isn't a circuit that can be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The wires of a loop of gates, starting and ending with the same wire.
+    Cycle(Vec<String>),
+    /// A gate reads a wire that is neither an input nor driven by a gate.
+    UndefinedWire { wire: String, reader: String },
+    /// A wire is driven by two inputs or gates.
+    DuplicateDriver {
+        wire: String,
+        first_line: usize,
+        line: usize,
+    },
+    /// The output of a gate doesn't reach any `z` wire.
+    UnreachableGate(String),
+}
+
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cycle(path) => write!(f, "cycle through `{}`", path.join(" -> ")),
+            Self::UndefinedWire { wire, reader } => write!(
+                f,
+                "`{reader}` reads `{wire}`, which is neither an input nor driven by a gate"
+            ),
+            Self::DuplicateDriver {
+                wire,
+                first_line,
+                line,
+            } => write!(
+                f,
+                "`{wire}` is driven on line {first_line} and again on line {line}"
+            ),
+            Self::UnreachableGate(wire) => {
+                write!(f, "the gate driving `{wire}` doesn't feed any output")
+            }
+        }
+    }
+}
+
+impl Error for CircuitError {}
+
+/// Why a device couldn't be loaded.
+#[derive(Debug, Clone)]
+pub enum LoadError {
+    Parse(ParseError),
+    Circuit(Vec<CircuitError>),
+}
+
+impl From<ParseError> for LoadError {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "{err}"),
+            Self::Circuit(errors) => {
+                write!(f, "invalid circuit:")?;
+                for err in errors {
+                    write!(f, "\n  - {err}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+impl Device {
+    /// The gates in a stable order, so that problems are always reported in the same order.
+    fn sorted_wires(&self) -> Vec<&str> {
+        let mut wires: Vec<_> = self.gates.keys().map(String::as_str).collect();
+        wires.sort();
+        wires
+    }
+
+    fn inputs_of(&self, wire: &str) -> Option<[&str; 2]> {
+        match self.gates.get(wire)? {
+            Gate::And(a, b) | Gate::Or(a, b) | Gate::Xor(a, b) => Some([a, b]),
+            Gate::Value(_) => None,
+        }
+    }
+
+    fn undefined_wires(&self) -> Vec<CircuitError> {
+        self.sorted_wires()
+            .into_iter()
+            .flat_map(|reader| {
+                self.inputs_of(reader)
+                    .into_iter()
+                    .flatten()
+                    .filter(|wire| !self.gates.contains_key(*wire))
+                    .map(move |wire| CircuitError::UndefinedWire {
+                        wire: wire.to_owned(),
+                        reader: reader.to_owned(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Every loop of gates found by a depth first search through the inputs of the gates.
+    fn cycles(&self) -> Vec<CircuitError> {
+        let mut done = HashSet::new();
+        let mut cycles = vec![];
+        for wire in self.sorted_wires() {
+            self.find_cycles(wire, &mut vec![], &mut done, &mut cycles);
+        }
+        cycles
+    }
+
+    fn find_cycles<'a>(
+        &'a self,
+        wire: &'a str,
+        path: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+        cycles: &mut Vec<CircuitError>,
+    ) {
+        if done.contains(wire) {
+            return;
+        }
+        if let Some(start) = path.iter().position(|&w| w == wire) {
+            // the path leads from a gate to its inputs, so it is reversed to follow the signal
+            let mut cycle: Vec<_> = path[start..].iter().rev().map(|w| w.to_string()).collect();
+            cycle.insert(0, wire.to_owned());
+            cycles.push(CircuitError::Cycle(cycle));
+            return;
+        }
+        path.push(wire);
+        for input in self.inputs_of(wire).into_iter().flatten() {
+            if self.gates.contains_key(input) {
+                self.find_cycles(input, path, done, cycles);
+            }
+        }
+        path.pop();
+        done.insert(wire);
+    }
+
+    /// Fails with the first problem that keeps the device from being evaluated.
+    pub(crate) fn check_evaluable(&self) -> Result<(), CircuitError> {
+        match self.undefined_wires().into_iter().next() {
+            Some(err) => Err(err),
+            None => self.cycles().into_iter().next().map_or(Ok(()), Err),
+        }
+    }
+
+    fn unreachable_gates(&self) -> Vec<CircuitError> {
+        let mut reached = HashSet::new();
+        let mut stack: Vec<_> = self
+            .gates
+            .keys()
+            .filter(|wire| wire.starts_with('z'))
+            .map(String::as_str)
+            .collect();
+        while let Some(wire) = stack.pop() {
+            if reached.insert(wire) {
+                stack.extend(self.inputs_of(wire).into_iter().flatten());
+            }
+        }
+        self.sorted_wires()
+            .into_iter()
+            .filter(|wire| self.inputs_of(wire).is_some() && !reached.contains(wire))
+            .map(|wire| CircuitError::UnreachableGate(wire.to_owned()))
+            .collect()
+    }
+
+    /// Checks that the device can be evaluated and that every gate matters, collecting every
+    /// problem found on top of the `duplicates` found while parsing.
+    pub(crate) fn validate(&self, duplicates: Vec<CircuitError>) -> Result<(), LoadError> {
+        let mut errors = duplicates;
+        errors.extend(self.undefined_wires());
+        errors.extend(self.cycles());
+        errors.extend(self.unreachable_gates());
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(LoadError::Circuit(errors)),
+        }
+    }
+}
+
+/// Remembers the line driving every wire, to report wires driven twice.
+#[derive(Debug, Default)]
+pub(crate) struct Drivers {
+    lines: HashMap<String, usize>,
+    duplicates: Vec<CircuitError>,
+}
+
+impl Drivers {
+    pub(crate) fn drive(&mut self, wire: &str, line: usize) {
+        match self.lines.get(wire) {
+            Some(&first_line) => self.duplicates.push(CircuitError::DuplicateDriver {
+                wire: wire.to_owned(),
+                first_line,
+                line,
+            }),
+            None => {
+                self.lines.insert(wire.to_owned(), line);
+            }
+        }
+    }
+
+    pub(crate) fn into_duplicates(self) -> Vec<CircuitError> {
+        self.duplicates
+    }
+}
@@ -0,0 +1,43 @@
+use day_24::Device;
+use support::device;
+
+mod support;
+
+#[test]
+fn blif_round_trips_gates_and_input_values() {
+    let device = device("input_swapped.txt");
+    let blif = device.to_blif("adder");
+    let reloaded = Device::from_blif(&blif).expect("Should be able to read BLIF back");
+
+    assert_eq!(reloaded.to_blif("adder"), blif);
+    let output = |device: &Device| device.compile().expect("Should compile").output();
+    assert_eq!(output(&reloaded), output(&device));
+}
+
+#[test]
+fn blif_reader_recognises_covers_written_differently() {
+    let blif = ".model tiny\n.inputs x00 y00\n.outputs z00 z01\n\
+                .names x00 y00 z00\n10 1\n01 1\n\
+                .names x00 y00 z01\n11 1\n.end\n";
+    let device = Device::from_blif(blif).expect("Should be able to read BLIF");
+    let circuit = device.compile().expect("Should compile");
+    assert_eq!(circuit.add(1, 1), 2);
+    assert_eq!(circuit.add(1, 0), 1);
+}
+
+#[test]
+fn verilog_has_buses_and_one_primitive_per_gate() {
+    let verilog = device("input_swapped.txt").to_verilog("adder");
+    assert!(verilog.starts_with("module adder(x, y, z);\n"));
+    assert!(verilog.contains("  input [44:0] x;\n  input [44:0] y;\n  output [45:0] z;\n"));
+    let primitives = verilog
+        .lines()
+        .filter(|line| {
+            ["  and g", "  or g", "  xor g"]
+                .iter()
+                .any(|p| line.starts_with(p))
+        })
+        .count();
+    assert_eq!(primitives, 222);
+    assert!(verilog.ends_with("endmodule\n"));
+}
@@ -0,0 +1,28 @@
+use support::device;
+
+mod support;
+
+#[test]
+fn bit_sliced_sums_match_single_sums() {
+    let mut state = 0x5eed_u64;
+    let mut next = || {
+        // a 64 bit linear congruential generator, the summands only need to differ
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        state >> 19
+    };
+    // two full chunks of 64 lanes and a partial one
+    let summands: Vec<_> = (0..150).map(|_| (next(), next())).collect();
+
+    for file in ["input.txt", "input_swapped.txt"] {
+        let circuit = device(file).compile().expect("Should compile");
+        let singles: Vec<_> = summands.iter().map(|&(x, y)| circuit.add(x, y)).collect();
+        assert_eq!(circuit.add_all(&summands), singles, "{file}");
+    }
+
+    // the real device adds, so the lanes can't all agree on something else
+    let circuit = device("input.txt").compile().expect("Should compile");
+    let sums: Vec<_> = summands.iter().map(|&(x, y)| x + y).collect();
+    assert_eq!(circuit.add_all(&summands), sums);
+}
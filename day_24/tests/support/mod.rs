@@ -0,0 +1,18 @@
+use std::path::Path;
+
+use day_24::Device;
+
+/// Reads a device description from `day_24/data`.
+pub fn source(file: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("data")
+        .join(file);
+    std::fs::read_to_string(path).expect("Should be able to read input")
+}
+
+/// Loads a device from `day_24/data`.
+pub fn device(file: &str) -> Device {
+    source(file)
+        .parse()
+        .expect("Should be able to parse device")
+}
@@ -0,0 +1,68 @@
+use day_24::{AdderCheck, Device};
+use support::{device, source};
+
+mod support;
+
+/// The device of `input.txt` with the outputs of wires `a` and `b` swapped.
+fn swapped(a: &str, b: &str) -> Device {
+    source("input.txt")
+        .replace(&format!("-> {a}\n"), "-> swap\n")
+        .replace(&format!("-> {b}\n"), &format!("-> {a}\n"))
+        .replace("-> swap\n", &format!("-> {b}\n"))
+        .parse()
+        .expect("Should be able to parse device")
+}
+
+#[test]
+fn low_output_bits_are_sum_bits() {
+    let z02 = device("input.txt")
+        .expression("z02")
+        .expect("Should expand");
+    assert_eq!(z02.to_string().matches("x00").count(), 1);
+}
+
+#[test]
+fn every_output_bit_of_an_adder_matches() {
+    assert_eq!(
+        device("input.txt").check_adder(1 << 12),
+        Ok(AdderCheck::Matches)
+    );
+}
+
+#[test]
+fn reports_first_bit_diverging_from_an_adder() {
+    let check = device("input_swapped.txt").check_adder(1 << 12);
+    assert!(matches!(check, Ok(AdderCheck::Diverges { bit: 13, .. })));
+}
+
+#[test]
+fn reports_divergences_in_high_bits() {
+    for (a, b, bit) in [("vwp", "z19", 19), ("mps", "z25", 25)] {
+        let check = swapped(a, b).check_adder(1 << 12);
+        assert!(
+            matches!(check, Ok(AdderCheck::Diverges { bit: found, .. }) if found == bit),
+            "{a} and {b} swapped: {check:?}"
+        );
+    }
+}
+
+#[test]
+fn shows_only_the_difference_of_a_divergence() {
+    let check = device("input_swapped.txt")
+        .check_adder(1 << 12)
+        .expect("Should check");
+    let shown = check.to_string();
+    assert!(shown.starts_with("z13 diverges from an adder:"), "{shown}");
+    assert!(shown.lines().count() == 3 && shown.len() < 1000, "{shown}");
+}
+
+#[test]
+fn handles_summands_wider_than_64_bits() {
+    let device: Device = "x64: 1\ny64: 0\n\nx64 XOR y64 -> z00\nx64 AND y64 -> z01"
+        .parse()
+        .expect("Should be able to parse device");
+    let z00 = device.expression("z00").expect("Should expand");
+    assert_eq!(z00.to_string(), "(x64 ^ y64)");
+    let check = device.check_adder(1 << 12);
+    assert!(matches!(check, Ok(AdderCheck::Diverges { bit: 0, .. })));
+}
@@ -0,0 +1,66 @@
+use day_24::{CircuitError, Device, LoadError};
+
+fn load_error(input: &str) -> String {
+    match input.parse::<Device>() {
+        Ok(_) => panic!("Device should be rejected"),
+        Err(err) => err.to_string(),
+    }
+}
+
+fn circuit_errors(input: &str) -> Vec<CircuitError> {
+    match input.parse::<Device>() {
+        Err(LoadError::Circuit(errors)) => errors,
+        Err(err) => panic!("Device should parse: {err}"),
+        Ok(_) => panic!("Device should be rejected"),
+    }
+}
+
+#[test]
+fn reports_circuit_cycle_instead_of_recursing_forever() {
+    let input = "x00: 1\ny00: 0\n\nx00 AND b -> a\na OR y00 -> b\na XOR y00 -> z00\n";
+    assert!(load_error(input).contains("cycle through `a -> b -> a`"));
+}
+
+#[test]
+fn reports_undefined_wire_instead_of_panicking() {
+    assert!(load_error("x00: 1\n\nx00 AND q -> z00\n")
+        .contains("`z00` reads `q`, which is neither an input nor driven by a gate"));
+}
+
+#[test]
+fn reports_lines_of_wires_driven_twice() {
+    let input = "x00: 1\ny00: 0\nx01: 1\n\n\
+                 x00 AND y00 -> z00\n\
+                 x00 OR y00 -> z00\n\
+                 x00 XOR y00 -> x01\n\
+                 x01 XOR y00 -> z01\n";
+    assert_eq!(
+        circuit_errors(input),
+        [
+            CircuitError::DuplicateDriver {
+                wire: "z00".to_owned(),
+                first_line: 5,
+                line: 6,
+            },
+            CircuitError::DuplicateDriver {
+                wire: "x01".to_owned(),
+                first_line: 3,
+                line: 7,
+            },
+        ]
+    );
+    assert!(load_error(input).contains("`z00` is driven on line 5 and again on line 6"));
+}
+
+#[test]
+fn reports_gates_not_feeding_any_output() {
+    let input = "x00: 1\ny00: 0\n\nx00 AND y00 -> z00\nx00 OR y00 -> b\nb XOR y00 -> a\n";
+    assert_eq!(
+        circuit_errors(input),
+        [
+            CircuitError::UnreachableGate("a".to_owned()),
+            CircuitError::UnreachableGate("b".to_owned()),
+        ]
+    );
+    assert!(load_error(input).contains("the gate driving `a` doesn't feed any output"));
+}
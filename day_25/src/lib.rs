@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use common::{Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Door {
+    locks: Vec<i32>,
+    keys: Vec<i32>,
+}
+
+impl FromStr for Door {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut locks = Vec::new();
+        let mut keys = Vec::new();
+
+        let lines: Vec<_> = s.lines().enumerate().collect();
+        for schematic in lines
+            .split(|(_, line)| line.is_empty())
+            .filter(|schematic| !schematic.is_empty())
+        {
+            let thing: Vec<Vec<i32>> = schematic
+                .iter()
+                .map(|&(idx, line)| {
+                    line.chars()
+                        .enumerate()
+                        .map(|(col, c)| match c {
+                            '.' => Ok(0),
+                            '#' => Ok(1),
+                            _ => Err(ParseError::new(idx + 1, col + 1, line, "`.` or `#`")),
+                        })
+                        .collect()
+                })
+                .collect::<Result<_, _>>()?;
+
+            let (first, first_line) = schematic[0];
+            let width = thing[0].len();
+            if thing.len() < 2 || width == 0 {
+                let expected = "a lock or key schematic";
+                return Err(ParseError::new(first + 1, 1, first_line, expected));
+            }
+            if let Some((&(idx, line), row)) = schematic
+                .iter()
+                .zip(&thing)
+                .find(|(_, row)| row.len() != width)
+            {
+                let column = row.len().min(width) + 1;
+                let expected = format!("{width} pins");
+                return Err(ParseError::new(idx + 1, column, line, expected));
+            }
+
+            let is_lock = thing[0][0] == 1;
+
+            let mut result = 0;
+
+            for col in 0..thing[0].len() {
+                for row in &thing[1..thing.len() - 1] {
+                    result <<= 1;
+                    result |= row[col];
+                }
+            }
+
+            if is_lock {
+                locks.push(result);
+            } else {
+                keys.push(result);
+            }
+        }
+
+        Ok(Self { locks, keys })
+    }
+}
+
+impl Door {
+    fn num_fitting_pairs(&self) -> usize {
+        self.locks
+            .iter()
+            .flat_map(|&lock| self.keys.iter().map(move |&key| (lock, key)))
+            .filter(|&(lock, key)| lock & key == 0)
+            .count()
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Door;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.num_fitting_pairs().into()
+    }
+}
@@ -0,0 +1,93 @@
+use std::{convert::Infallible, iter::once};
+
+use common::{Answer, Solution};
+use regex::{Captures, Regex};
+
+#[derive(Debug)]
+pub struct Multiply {
+    factor1: i32,
+    factor2: i32,
+}
+
+impl Multiply {
+    fn product(&self) -> i32 {
+        self.factor1 * self.factor2
+    }
+}
+
+impl From<Captures<'_>> for Multiply {
+    fn from(value: Captures<'_>) -> Self {
+        let (_, [first, second]) = value.extract();
+        Multiply {
+            factor1: first.parse().expect("Should be able to parse first factor"),
+            factor2: second
+                .parse()
+                .expect("Should be able to parse second factor"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Token {
+    Do,
+    Dont,
+    Mul(Multiply),
+}
+
+impl From<Captures<'_>> for Token {
+    fn from(value: Captures<'_>) -> Self {
+        let tokens = value.iter().skip(1).collect::<Vec<_>>();
+        match &tokens[..] {
+            [Some(_), None, None, None] => Token::Do,
+            [None, Some(_), None, None] => Token::Dont,
+            [None, None, Some(first), Some(second)] => Token::Mul(Multiply {
+                factor1: first
+                    .as_str()
+                    .parse()
+                    .expect("Should be able to parse first factor"),
+                factor2: second
+                    .as_str()
+                    .parse()
+                    .expect("Should be able to parse second factor"),
+            }),
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Token>;
+    type Err = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        let regex = Regex::new(r"(do\(\))|(don't\(\))|mul\((\d{1,3}),(\d{1,3})\)")
+            .expect("Regex should be valid");
+
+        Ok(regex.captures_iter(input).map(|c| c.into()).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .filter_map(|t| match t {
+                Token::Mul(v) => Some(v.product()),
+                _ => None,
+            })
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        once(&Token::Do)
+            .chain(input.iter())
+            .rfold((0, 0), |(tmp, acc), val| match val {
+                Token::Mul(v) => (tmp + v.product(), acc),
+                Token::Dont => (0, acc),
+                Token::Do => (0, acc + tmp),
+            })
+            .1
+            .into()
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Character {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug)]
+struct ParseCharacterError;
+
+impl TryFrom<char> for Character {
+    type Error = ParseCharacterError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'X' => Ok(Character::X),
+            'M' => Ok(Character::M),
+            'A' => Ok(Character::A),
+            'S' => Ok(Character::S),
+            _ => Err(ParseCharacterError),
+        }
+    }
+}
+
+impl Display for ParseCharacterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "one of `X`, `M`, `A` or `S`")
+    }
+}
+
+#[derive(Debug)]
+pub struct WordSearch {
+    lines: Grid<Character>,
+}
+
+impl FromStr for WordSearch {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(|lines| Self { lines })
+    }
+}
+
+impl WordSearch {
+    fn matches_sequence(&self, seq: &[Character], start: Point, direction: Direction) -> bool {
+        let mut ray = self.lines.ray(start, direction).map(|(_, &c)| c);
+        seq.iter().all(|&c| ray.next() == Some(c))
+    }
+
+    fn count_matches_at(&self, seq: &[Character], point: Point) -> usize {
+        Direction::ALL
+            .into_iter()
+            .filter(|&direction| self.matches_sequence(seq, point, direction))
+            .count()
+    }
+
+    fn count_matches(&self, seq: &[Character]) -> usize {
+        self.lines
+            .points()
+            .map(|point| self.count_matches_at(seq, point))
+            .sum()
+    }
+
+    fn check_x_mas(&self, point: Point) -> bool {
+        if self.lines[point] != Character::A {
+            return false;
+        }
+        let corners = [
+            Direction::UpLeft,
+            Direction::UpRight,
+            Direction::DownRight,
+            Direction::DownLeft,
+        ]
+        .map(|direction| self.lines.get(point + direction).copied());
+
+        matches!(
+            corners,
+            [
+                Some(Character::M),
+                Some(Character::M),
+                Some(Character::S),
+                Some(Character::S)
+            ] | [
+                Some(Character::S),
+                Some(Character::M),
+                Some(Character::M),
+                Some(Character::S)
+            ] | [
+                Some(Character::S),
+                Some(Character::S),
+                Some(Character::M),
+                Some(Character::M)
+            ] | [
+                Some(Character::M),
+                Some(Character::S),
+                Some(Character::S),
+                Some(Character::M)
+            ]
+        )
+    }
+
+    fn check_x_mases(&self) -> usize {
+        self.lines
+            .points()
+            .filter(|&point| self.check_x_mas(point))
+            .count()
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = WordSearch;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let seq = [Character::X, Character::M, Character::A, Character::S];
+        input.count_matches(&seq).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.check_x_mases().into()
+    }
+}
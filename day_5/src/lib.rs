@@ -0,0 +1,226 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use common::{Answer, ParseError, Solution};
+
+#[derive(Debug)]
+struct PageRules {
+    is_before: HashMap<usize, Vec<usize>>,
+    is_after: HashMap<usize, Vec<usize>>,
+}
+
+impl From<Vec<OrderingRule>> for PageRules {
+    fn from(value: Vec<OrderingRule>) -> Self {
+        let mut is_before = HashMap::new();
+        let mut is_after = HashMap::new();
+
+        for rule in value {
+            is_before
+                .entry(rule.page_number_before)
+                .or_insert_with(Vec::new)
+                .push(rule.page_number_after);
+
+            is_after
+                .entry(rule.page_number_after)
+                .or_insert_with(Vec::new)
+                .push(rule.page_number_before);
+        }
+
+        Self {
+            is_before,
+            is_after,
+        }
+    }
+}
+
+impl PageRules {
+    fn is_order_valid(&self, order: &[usize]) -> bool {
+        (0..order.len()).all(|i| {
+            let curr = order[i];
+
+            let pages_before_forced = self.is_after.get(&curr);
+            let pages_after_forced = self.is_before.get(&curr);
+
+            let violates_pages_before = pages_before_forced.is_some_and(|pages_before_forced| {
+                order[i + 1..]
+                    .iter()
+                    .any(|next| pages_before_forced.contains(next))
+            });
+
+            let violates_pages_after = pages_after_forced.is_some_and(|pages_after_forced| {
+                order[..i]
+                    .iter()
+                    .any(|prev| pages_after_forced.contains(prev))
+            });
+
+            !violates_pages_before && !violates_pages_after
+        })
+    }
+}
+
+#[derive(Debug)]
+struct OrderingRule {
+    page_number_before: usize,
+    page_number_after: usize,
+}
+
+fn parse_page_number(line: &str, token: &str) -> Result<usize, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at_token(line, token, "a page number"))
+}
+
+impl FromStr for OrderingRule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = s
+            .split_once('|')
+            .ok_or_else(|| ParseError::at_end(s, "`|`"))?;
+        Ok(Self {
+            page_number_before: parse_page_number(s, first)?,
+            page_number_after: parse_page_number(s, second)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Update {
+    page_numbers: Vec<usize>,
+}
+
+impl FromStr for Update {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let page_numbers: Vec<_> = s
+            .split(',')
+            .map(|page_number| parse_page_number(s, page_number))
+            .collect::<Result<_, _>>()?;
+        // an update of an even number of pages has no middle page
+        if page_numbers.len() % 2 == 0 {
+            return Err(ParseError::at_end(s, "an odd number of pages"));
+        }
+        Ok(Self { page_numbers })
+    }
+}
+
+impl Update {
+    fn get_middle_page(&self) -> usize {
+        self.page_numbers[self.page_numbers.len() / 2]
+    }
+
+    fn reorder(&self, page_rules: &PageRules) -> Vec<usize> {
+        let comes_before_rules: HashMap<usize, HashSet<usize>> = page_rules
+            .is_after
+            .iter()
+            .map(|(&key, values)| (key, values.iter().copied().collect::<HashSet<_>>()))
+            .collect();
+
+        let pages: HashSet<_> = self.page_numbers.iter().copied().collect();
+        let mut result = Vec::with_capacity(self.page_numbers.len());
+        let mut step = 0;
+
+        while result.len() != self.page_numbers.len() {
+            // println!("result: {:?}", result);
+            if step >= pages.len() {
+                panic!("too many iterations. We should be able to place at least one element per iteration");
+            } else {
+                step += 1;
+            }
+            let already_inserted: HashSet<_> = result.iter().copied().collect();
+            let to_be_inserted: HashSet<_> = pages.difference(&already_inserted).copied().collect();
+            to_be_inserted.iter().for_each(|&number| {
+                if let Some(rules) = comes_before_rules.get(&number) {
+                    let rules_that_apply: Vec<_> = rules.intersection(&to_be_inserted).collect();
+                    // println!("rules that apply to {}: {:?}", number, rules_that_apply);
+                    if rules_that_apply.is_empty() {
+                        result.push(number);
+                    }
+                } else {
+                    result.push(number);
+                }
+            });
+        }
+
+        if page_rules.is_order_valid(&result) {
+            result
+        } else {
+            panic!("ordering didn't work")
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Manual {
+    page_rules: PageRules,
+    updates: Vec<Update>,
+}
+
+impl FromStr for Manual {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate();
+        let ordering_rules: Vec<OrderingRule> = (&mut lines)
+            .take_while(|(_, line)| !line.is_empty())
+            .map(|(idx, line)| line.parse().map_err(|err: ParseError| err.on_line(idx + 1)))
+            .collect::<Result<_, _>>()?;
+        let updates = lines
+            .map(|(idx, line)| line.parse().map_err(|err: ParseError| err.on_line(idx + 1)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            page_rules: ordering_rules.into(),
+            updates,
+        })
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Manual;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .updates
+            .iter()
+            .filter_map(|update| {
+                if input.page_rules.is_order_valid(&update.page_numbers) {
+                    Some(update.get_middle_page())
+                } else {
+                    None
+                }
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .updates
+            .iter()
+            .filter_map(|update| {
+                if !input.page_rules.is_order_valid(&update.page_numbers) {
+                    let updated_order = update.reorder(&input.page_rules);
+                    Some(
+                        Update {
+                            page_numbers: updated_order,
+                        }
+                        .get_middle_page(),
+                    )
+                } else {
+                    None
+                }
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
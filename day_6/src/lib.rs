@@ -0,0 +1,201 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Formatter, Write},
+    str::FromStr,
+};
+
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point};
+
+#[derive(Debug, Clone)]
+struct Guard {
+    direction: Direction,
+    position: Point,
+}
+
+enum StepError {
+    OutOfBounds,
+    Loop,
+}
+
+impl Guard {
+    fn try_step(&self, lab: &mut Lab) -> Result<Self, StepError> {
+        if !lab.guard_history.insert(GuardHistory {
+            position: self.position,
+            dir: self.direction,
+        }) {
+            return Err(StepError::Loop);
+        }
+        let position_new = self.position + self.direction;
+
+        match lab.map.get(position_new) {
+            Some(LabTile::Floor) => {
+                lab.map[position_new] = LabTile::Visited;
+            }
+            Some(LabTile::Visited) => {}
+            Some(LabTile::Obstacle) => {
+                let mut guard_new = self.clone();
+                guard_new.direction = guard_new.direction.turn_right();
+                return guard_new.try_step(lab);
+            }
+            None => return Err(StepError::OutOfBounds),
+        }
+        Ok(Self {
+            position: position_new,
+            direction: self.direction,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum LabTile {
+    Floor,
+    Obstacle,
+    Visited,
+}
+
+impl Display for LabTile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(match self {
+            Self::Floor => '.',
+            Self::Obstacle => '#',
+            Self::Visited => 'X',
+        })
+    }
+}
+
+struct ParseLabTileError;
+
+impl TryFrom<char> for LabTile {
+    type Error = ParseLabTileError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Floor),
+            '#' => Ok(Self::Obstacle),
+            '^' => Ok(Self::Visited),
+            _ => Err(ParseLabTileError),
+        }
+    }
+}
+
+impl Display for ParseLabTileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "one of `.`, `#` or `^`")
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
+struct GuardHistory {
+    position: Point,
+    dir: Direction,
+}
+
+#[derive(Debug, Clone)]
+pub struct Lab {
+    map: Grid<LabTile>,
+    guard: Guard,
+    guard_history: HashSet<GuardHistory>,
+}
+
+impl FromStr for Lab {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map: Grid<LabTile> = s.parse()?;
+        let position = map
+            .position(|&tile| tile == LabTile::Visited)
+            .ok_or_else(|| {
+                ParseError::new(1, 1, s.lines().next().unwrap_or_default(), "a guard `^`")
+            })?;
+        Ok(Self {
+            map,
+            guard: Guard {
+                direction: Direction::Up,
+                position,
+            },
+            guard_history: HashSet::new(),
+        })
+    }
+}
+
+impl Lab {
+    fn forward_time(&mut self) -> bool {
+        let mut guard = self.guard.clone();
+        loop {
+            match guard.try_step(self) {
+                Ok(guard_new) => guard = guard_new,
+                Err(StepError::Loop) => return true,
+                Err(StepError::OutOfBounds) => return false,
+            }
+        }
+    }
+
+    fn patrolled(&self) -> Lab {
+        let mut lab = self.clone();
+        let loop_detected = lab.forward_time();
+        if loop_detected {
+            panic!("Initial run must not contain a loop");
+        }
+        lab
+    }
+}
+
+impl Display for Lab {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (position, tile) in self.map.iter() {
+            if position.x == 0 && position.y != 0 {
+                writeln!(f)?;
+            }
+            if position == self.guard.position {
+                match self.guard.direction {
+                    Direction::Down => f.write_char('v')?,
+                    Direction::Up => f.write_char('^')?,
+                    Direction::Left => f.write_char('<')?,
+                    Direction::Right => f.write_char('>')?,
+                    _ => unreachable!("the guard only walks along the axes"),
+                }
+                continue;
+            }
+            write!(f, "{}", tile)?;
+        }
+        writeln!(f)
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Lab;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .patrolled()
+            .map
+            .iter()
+            .filter(|(_, &tile)| tile == LabTile::Visited)
+            .count()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .patrolled()
+            .map
+            .iter()
+            .filter(|&(position, &tile)| {
+                tile == LabTile::Visited && position != input.guard.position
+            })
+            .filter(|&(position, _)| {
+                let mut lab = input.clone();
+                lab.map[position] = LabTile::Obstacle;
+
+                lab.forward_time()
+            })
+            .count()
+            .into()
+    }
+}
@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use common::{parse_lines, Answer, ParseError, Solution};
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug)]
+enum Operator {
+    Add,
+    Multiply,
+    Concatenate,
+}
+
+impl Operator {
+    fn apply(&self, lhs: i64, rhs: i64) -> i64 {
+        match self {
+            Operator::Add => lhs + rhs,
+            Operator::Multiply => lhs * rhs,
+            Operator::Concatenate => {
+                assert_ne!(rhs.signum(), -1, "right hand side must not be negative");
+                let mut s = lhs.to_string();
+                s.push_str(&rhs.to_string());
+                s.parse()
+                    .expect("Concatenation of two i64 should be an i64")
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Equation {
+    result: i64,
+    values: Vec<i64>,
+}
+
+impl FromStr for Equation {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (result, values) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at_end(s, "`: `"))?;
+        let result = result
+            .parse()
+            .map_err(|_| ParseError::at_token(s, result, "a test value"))?;
+        let values: Vec<_> = values
+            .split_ascii_whitespace()
+            .map(|v| {
+                v.parse()
+                    .map_err(|_| ParseError::at_token(s, v, "a number"))
+            })
+            .collect::<Result<_, _>>()?;
+        if values.is_empty() {
+            return Err(ParseError::at_end(s, "a value"));
+        }
+        Ok(Self { result, values })
+    }
+}
+
+impl Equation {
+    fn is_valid(&self, ops: &[Operator]) -> bool {
+        if ops.len() + 1 != self.values.len() {
+            panic!("invalid number of operators");
+        }
+        self.values
+            .iter()
+            .skip(1)
+            .zip(ops.iter())
+            .fold((self.values[0], Operator::Add), |(acc, _), (&val, &op)| {
+                (op.apply(acc, val), op)
+            })
+            .0
+            == self.result
+    }
+
+    fn is_solvable(&self) -> bool {
+        let ops_count = self.values.len() - 1;
+        (0..2usize.pow(ops_count.try_into().unwrap())).any(|lookup| {
+            let ops: Vec<_> = (0..ops_count)
+                .map(move |shift| {
+                    if lookup & (1 << shift) == 0 {
+                        Operator::Add
+                    } else {
+                        Operator::Multiply
+                    }
+                })
+                .collect();
+
+            self.is_valid(&ops)
+        })
+    }
+
+    fn is_solvable_with_concatenation(&self) -> bool {
+        let ops_count = self.values.len() - 1;
+        let all_ops = [Operator::Add, Operator::Multiply, Operator::Concatenate];
+        (0..ops_count)
+            .map(|_| all_ops.iter().copied())
+            .multi_cartesian_product()
+            .any(|ops| self.is_valid(&ops))
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .filter(|eq| eq.is_solvable())
+            .map(|eq| eq.result)
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .filter(|eq| eq.is_solvable_with_concatenation())
+            .map(|eq| eq.result)
+            .sum::<i64>()
+            .into()
+    }
+}
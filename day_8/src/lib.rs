@@ -0,0 +1,159 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spot {
+    Empty,
+    Antenna(char),
+}
+
+struct ParseSpotError;
+
+impl TryFrom<char> for Spot {
+    type Error = ParseSpotError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Spot::Empty),
+            c if c.is_ascii_alphanumeric() => Ok(Spot::Antenna(c)),
+            _ => Err(ParseSpotError),
+        }
+    }
+}
+
+impl Display for ParseSpotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`.` or an alphanumeric antenna frequency")
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Antenna {
+    frequency: char,
+    position: Point,
+}
+
+impl Hash for Antenna {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.position.hash(state);
+    }
+}
+
+impl PartialEq for Antenna {
+    fn eq(&self, other: &Antenna) -> bool {
+        self.position == other.position
+    }
+}
+
+impl Eq for Antenna {}
+
+#[derive(Debug)]
+pub struct City {
+    map: Grid<Spot>,
+    antennas: Vec<Antenna>,
+}
+
+impl FromStr for City {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map: Grid<Spot> = s.parse()?;
+        let antennas = map
+            .iter()
+            .filter_map(|(position, &spot)| match spot {
+                Spot::Empty => None,
+                Spot::Antenna(frequency) => Some(Antenna {
+                    position,
+                    frequency,
+                }),
+            })
+            .collect();
+        Ok(Self { map, antennas })
+    }
+}
+
+impl City {
+    fn antinodes(&self) -> HashSet<Antenna> {
+        let mut result = HashSet::new();
+
+        for (frequency, chunk) in &self
+            .antennas
+            .iter()
+            .sorted_by_key(|a| a.frequency)
+            .chunk_by(|a| a.frequency)
+        {
+            for combination in chunk.combinations(2) {
+                let [a, b] = combination[..] else {
+                    panic!("found a pair consisting of some other number, but not two, elements. Hopefully I'll get a nobel prize for this");
+                };
+                let diff = a.position - b.position;
+                for position in [a.position + diff, b.position - diff] {
+                    if self.map.contains(position) {
+                        result.insert(Antenna {
+                            frequency,
+                            position,
+                        });
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn antinodes_resonant_harmonics(&self) -> HashSet<Antenna> {
+        let mut result = HashSet::new();
+
+        for (frequency, chunk) in &self
+            .antennas
+            .iter()
+            .sorted_by_key(|a| a.frequency)
+            .chunk_by(|a| a.frequency)
+        {
+            for combination in chunk.combinations(2) {
+                let [a, b] = combination[..] else {
+                    panic!("found a pair consisting of some other number, but not two, elements. Hopefully I'll get a nobel prize for this");
+                };
+                let diff = a.position - b.position;
+
+                for step in [diff, -diff] {
+                    let mut position = a.position;
+                    while self.map.contains(position) {
+                        result.insert(Antenna {
+                            frequency,
+                            position,
+                        });
+                        position += step;
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = City;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.antinodes().len().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.antinodes_resonant_harmonics().len().into()
+    }
+}
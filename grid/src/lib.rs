@@ -0,0 +1,5 @@
+mod grid;
+mod point;
+
+pub use grid::Grid;
+pub use point::{Direction, Point};
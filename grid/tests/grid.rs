@@ -0,0 +1,90 @@
+use grid::{Direction, Grid, Point};
+
+fn digits(s: &str) -> Grid<u32> {
+    Grid::parse_with(s, |c| c.to_digit(10).ok_or("a digit")).expect("Grid should parse")
+}
+
+#[test]
+fn parses_row_by_row() {
+    let grid = digits("123\n456\n");
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(2, 0)], 3);
+    assert_eq!(grid[Point::new(0, 1)], 4);
+    assert_eq!(grid.position(|&d| d == 5), Some(Point::new(1, 1)));
+}
+
+#[test]
+fn rejects_ragged_lines() {
+    let err = Grid::<char>::parse_with("abc\nab\n", Ok::<_, &str>).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.expected, "a line of 3 tiles");
+
+    let err = Grid::<char>::parse_with("ab\nabc\n", Ok::<_, &str>).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.expected, "the end of the line");
+}
+
+#[test]
+fn reports_the_position_of_invalid_tiles() {
+    let err = Grid::parse_with("12\n3x\n", |c| c.to_digit(10).ok_or("a digit")).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!(err.expected, "a digit");
+}
+
+#[test]
+fn neighbours_stay_within_the_grid() {
+    let grid = digits("123\n456\n789\n");
+    let corner: Vec<_> = grid.neighbours4(Point::new(0, 0)).collect();
+    assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+    assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbours8(Point::new(2, 1)).count(), 5);
+    assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+    assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+    // points outside the grid only have neighbours inside it
+    assert_eq!(
+        grid.neighbours4(Point::new(-1, 0)).collect::<Vec<_>>(),
+        [Point::new(0, 0)]
+    );
+}
+
+#[test]
+fn rays_stop_at_the_edge() {
+    let grid = digits("123\n456\n789\n");
+    let cells = |start, direction| -> Vec<u32> {
+        grid.ray(start, direction).map(|(_, &cell)| cell).collect()
+    };
+    assert_eq!(cells(Point::new(0, 0), Direction::Right), [1, 2, 3]);
+    assert_eq!(cells(Point::new(0, 0), Direction::DownRight), [1, 5, 9]);
+    assert_eq!(cells(Point::new(0, 2), Direction::UpRight), [7, 5, 3]);
+    assert_eq!(cells(Point::new(1, 2), Direction::Up), [8, 5, 2]);
+    assert_eq!(cells(Point::new(0, 1), Direction::Left), [4]);
+    assert!(cells(Point::new(3, 0), Direction::Left).is_empty());
+}
+
+#[test]
+fn turns_clockwise_and_counterclockwise() {
+    let mut direction = Direction::Up;
+    for expected in [
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+        Direction::Up,
+    ] {
+        direction = direction.turn_right();
+        assert_eq!(direction, expected);
+    }
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::UpRight.turn_left(), Direction::UpLeft);
+    assert_eq!(Direction::DownLeft.turn_right(), Direction::UpLeft);
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.opposite().offset(), -direction.offset());
+    }
+}
+
+#[test]
+fn displays_row_by_row() {
+    let grid: Grid<char> = "#.\n.#\n".parse().expect("Grid should parse");
+    assert_eq!(grid.to_string(), "#.\n.#\n");
+    assert_eq!(grid.map(|&c| (c == '#') as u8).to_string(), "10\n01\n");
+}